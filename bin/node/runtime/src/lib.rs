@@ -126,10 +126,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 268,
+	spec_version: 269,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -1475,29 +1475,58 @@ impl pallet_gilt::Config for Runtime {
 parameter_types! {
 	pub const CollectionDeposit: Balance = 100 * DOLLARS;
 	pub const ItemDeposit: Balance = 1 * DOLLARS;
+	pub const ItemNameLimit: u32 = 64;
 	pub const KeyLimit: u32 = 32;
 	pub const ValueLimit: u32 = 256;
+	pub const UniquesPalletId: PalletId = PalletId(*b"py/uniqs");
+	pub const EscrowTimeout: BlockNumber = 7 * DAYS;
+	pub const MintStakeCooldown: BlockNumber = 1 * DAYS;
+	pub const MaxEscrowsPerBlock: u32 = 64;
+	pub const MaxRecurringLocks: u32 = 64;
+	pub const MaxApprovalsPerItem: u32 = 10;
+	pub const MaxRoleAssignments: u32 = 64;
+	pub const MaxItemsPerQuery: u32 = 256;
+	pub const MaxMarketplaces: u32 = 16;
+	pub const MaxItemsPerTransfer: u32 = 64;
+	pub const BondReleaseCooldown: BlockNumber = 28 * DAYS;
 }
 
 impl pallet_uniques::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollectionId = u32;
 	type ItemId = u32;
+	type CollectionIdConverter = ();
 	type Currency = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type CollectionDeposit = CollectionDeposit;
+	type CollectionDepositTiers = pallet_uniques::EscalatingDeposit<ConstU32<10>>;
 	type ItemDeposit = ItemDeposit;
 	type MetadataDepositBase = MetadataDepositBase;
 	type AttributeDepositBase = MetadataDepositBase;
 	type DepositPerByte = MetadataDepositPerByte;
 	type StringLimit = StringLimit;
+	type ItemNameLimit = ItemNameLimit;
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
+	type PalletId = UniquesPalletId;
+	type EscrowTimeout = EscrowTimeout;
+	type MintStakeCooldown = MintStakeCooldown;
+	type MaxEscrowsPerBlock = MaxEscrowsPerBlock;
+	type MaxRecurringLocks = MaxRecurringLocks;
+	type MaxApprovalsPerItem = MaxApprovalsPerItem;
+	type MaxRoleAssignments = MaxRoleAssignments;
+	type MaxItemsPerQuery = MaxItemsPerQuery;
+	type MaxMarketplaces = MaxMarketplaces;
+	type MaxItemsPerTransfer = MaxItemsPerTransfer;
+	type BondReleaseCooldown = BondReleaseCooldown;
+	type BondSlashed = Treasury;
+	type FeeCollector = Treasury;
 	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
 	type Locker = ();
+	type TransferPolicy = ();
 }
 
 impl pallet_transaction_storage::Config for Runtime {
@@ -1722,6 +1751,7 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_uniques::migration::MigrateToLatest<Runtime>,
 );
 
 /// MMR helper types.
@@ -1887,6 +1917,80 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_uniques_runtime_api::UniquesApi<Block, AccountId, u32, u32, Balance, BlockNumber>
+		for Runtime
+	{
+		fn owner(collection: u32, item: u32) -> Option<AccountId> {
+			Uniques::owner(collection, item)
+		}
+
+		fn collection_owner(collection: u32) -> Option<AccountId> {
+			Uniques::collection_owner(collection)
+		}
+
+		fn is_collection_owner(collection: u32, who: AccountId) -> bool {
+			Uniques::is_collection_owner(collection, &who)
+		}
+
+		fn canonical_collection_id(collection: u32) -> Option<u128> {
+			Uniques::canonical_collection_id(collection)
+		}
+
+		fn collection_from_canonical(canonical: u128) -> Option<u32> {
+			Uniques::collection_from_canonical(canonical)
+		}
+
+		fn item_locked_funds(collection: u32, item: u32) -> Balance {
+			Uniques::item_locked_funds(collection, item)
+		}
+
+		fn item_trading_state(
+			collection: u32,
+			item: u32,
+		) -> pallet_uniques_runtime_api::ItemTradingState<AccountId, Balance> {
+			Uniques::item_trading_state(collection, item)
+		}
+
+		fn item_name(collection: u32, item: u32) -> Option<Vec<u8>> {
+			Uniques::item_name(collection, item)
+		}
+
+		fn item_by_name(collection: u32, name: Vec<u8>) -> Option<u32> {
+			Uniques::item_by_name(collection, name)
+		}
+
+		fn are_settings_locked(collection: u32) -> bool {
+			Uniques::are_settings_locked(collection)
+		}
+
+		fn owners_of(
+			collection: u32,
+			items: Vec<u32>,
+		) -> Result<Vec<(u32, Option<AccountId>)>, sp_runtime::DispatchError> {
+			Uniques::owners_of(collection, items)
+		}
+
+		fn item_transfer_count(collection: u32, item: u32) -> u32 {
+			Uniques::item_transfer_count(collection, item)
+		}
+
+		fn collection_config_raw(collection: u32) -> Option<Vec<u8>> {
+			Uniques::collection_config_raw(collection)
+		}
+
+		fn is_approved(collection: u32, item: u32, delegate: AccountId) -> bool {
+			Uniques::is_approved(collection, item, &delegate)
+		}
+
+		fn next_item_id(collection: u32) -> Option<u32> {
+			Uniques::next_item_id(collection)
+		}
+
+		fn item_minted_at(collection: u32, item: u32) -> Option<BlockNumber> {
+			Uniques::item_minted_at(collection, item)
+		}
+	}
+
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
 		fn configuration() -> sp_consensus_babe::BabeConfiguration {
 			let epoch_config = Babe::epoch_config().unwrap_or(BABE_GENESIS_EPOCH_CONFIG);
@@ -0,0 +1,103 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for uniques pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_uniques::ItemTradingState;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for accessing information about uniques.
+	pub trait UniquesApi<AccountId, CollectionId, ItemId, Balance, BlockNumber>
+		where
+			AccountId: Codec,
+			CollectionId: Codec,
+			ItemId: Codec,
+			Balance: Codec,
+			BlockNumber: Codec,
+	{
+		/// Returns the owner of the given item, if it exists.
+		fn owner(collection: CollectionId, item: ItemId) -> Option<AccountId>;
+
+		/// Returns the owner of the given collection, if it exists.
+		fn collection_owner(collection: CollectionId) -> Option<AccountId>;
+
+		/// Returns `true` if `who` owns the given collection.
+		fn is_collection_owner(collection: CollectionId, who: AccountId) -> bool;
+
+		/// Returns the canonical `u128` representation of the given collection id, if it has one.
+		fn canonical_collection_id(collection: CollectionId) -> Option<u128>;
+
+		/// Returns the collection id represented by the given canonical `u128`, if any.
+		fn collection_from_canonical(canonical: u128) -> Option<CollectionId>;
+
+		/// Returns the funds currently locked against the given item, zero if there are none.
+		fn item_locked_funds(collection: CollectionId, item: ItemId) -> Balance;
+
+		/// Returns the listing and the open escrow of the given item, if there are any.
+		fn item_trading_state(
+			collection: CollectionId,
+			item: ItemId,
+		) -> ItemTradingState<AccountId, Balance>;
+
+		/// Returns the on-chain name of the given item, if it has one.
+		fn item_name(collection: CollectionId, item: ItemId) -> Option<Vec<u8>>;
+
+		/// Returns the item carrying the given name, within a collection with unique item names.
+		fn item_by_name(collection: CollectionId, name: Vec<u8>) -> Option<ItemId>;
+
+		/// Returns `true` if the configuration of the given collection is final.
+		fn are_settings_locked(collection: CollectionId) -> bool;
+
+		/// Returns the owners of the given items, in the same order, `None` for missing items.
+		///
+		/// Fails if more than `MaxItemsPerQuery` items are given.
+		fn owners_of(
+			collection: CollectionId,
+			items: Vec<ItemId>,
+		) -> Result<Vec<(ItemId, Option<AccountId>)>, DispatchError>;
+
+		/// Returns the number of times the given item has been transferred since it was minted.
+		fn item_transfer_count(collection: CollectionId, item: ItemId) -> u32;
+
+		/// Returns the SCALE encoded configuration of the given collection, as it is stored.
+		///
+		/// Lets clients decode the configuration with their own types, e.g. around an upgrade
+		/// which changes its layout. `None` if the collection has the default configuration.
+		fn collection_config_raw(collection: CollectionId) -> Option<Vec<u8>>;
+
+		/// Returns `true` if the given delegate may transfer the given item at the current block.
+		///
+		/// Approvals past their deadline count as revoked, even while still stored.
+		fn is_approved(collection: CollectionId, item: ItemId, delegate: AccountId) -> bool;
+
+		/// Returns the id the next sequential mint of the given collection would get.
+		///
+		/// `None` unless the collection allocates its item ids sequentially.
+		fn next_item_id(collection: CollectionId) -> Option<ItemId>;
+
+		/// Returns the block in which the given item was minted.
+		///
+		/// `None` for missing items and for items minted before mint blocks were recorded.
+		fn item_minted_at(collection: CollectionId, item: ItemId) -> Option<BlockNumber>;
+	}
+}
@@ -26,10 +26,10 @@ use frame_benchmarking::{
 use frame_support::{
 	dispatch::UnfilteredDispatchable,
 	traits::{EnsureOrigin, Get},
-	BoundedVec,
+	BoundedBTreeSet, BoundedVec,
 };
 use frame_system::RawOrigin as SystemOrigin;
-use sp_runtime::traits::Bounded;
+use sp_runtime::traits::{Bounded, Hash, One};
 use sp_std::prelude::*;
 
 use crate::Pallet as Uniques;
@@ -101,6 +101,7 @@ fn add_item_metadata<T: Config<I>, I: 'static>(
 		item,
 		vec![0; T::StringLimit::get() as usize].try_into().unwrap(),
 		false,
+		None,
 	)
 	.is_ok());
 	(caller, caller_lookup)
@@ -126,6 +127,25 @@ fn add_item_attribute<T: Config<I>, I: 'static>(
 	(key, caller, caller_lookup)
 }
 
+fn open_item_escrow<T: Config<I>, I: 'static>(
+	item: T::ItemId,
+) -> (T::AccountId, T::AccountId, ItemPrice<T, I>) {
+	let seller = Item::<T, I>::get(T::Helper::collection(0), item).unwrap().owner;
+	let buyer: T::AccountId = account("buyer", 0, SEED);
+	let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+	let price = ItemPrice::<T, I>::from(100u32);
+	T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value() / 2u32.into());
+	assert!(Uniques::<T, I>::open_escrow(
+		SystemOrigin::Signed(seller.clone()).into(),
+		T::Helper::collection(0),
+		item,
+		price,
+		buyer_lookup,
+	)
+	.is_ok());
+	(seller, buyer, price)
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -180,6 +200,18 @@ benchmarks_instance_pallet! {
 
 	mint {
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		Uniques::<T, I>::do_set_collection_config(
+			collection,
+			CollectionConfig {
+				settings: CollectionSettings(CollectionSetting::StampMintProvenance.into()),
+				max_mints_per_block: Some(u32::MAX),
+				mint_min_stake: Some(T::Currency::minimum_balance()),
+				mint_cooldown_per_account: Some(One::one()),
+				max_distinct_owners: Some(u32::MAX),
+				..Default::default()
+			},
+			None,
+		)?;
 		let item = T::Helper::item(0);
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, caller_lookup)
 	verify {
@@ -189,9 +221,34 @@ benchmarks_instance_pallet! {
 	burn {
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
+		let (key, ..) = add_item_attribute::<T, I>(item);
+		let value = vec![0; T::ValueLimit::get() as usize].try_into().unwrap();
+		Uniques::<T, I>::do_set_burn_requirement(
+			collection,
+			Some(BurnRequirement { key, value }),
+			None,
+		)?;
+		Uniques::<T, I>::do_set_collection_config(
+			collection,
+			CollectionConfig {
+				settings: CollectionSettings(CollectionSetting::UniqueItemNames.into()),
+				..Default::default()
+			},
+			None,
+		)?;
+		let name = vec![0u8; T::ItemNameLimit::get() as usize].try_into().unwrap();
+		Uniques::<T, I>::do_set_item_name(collection, item, name, None)?;
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(caller_lookup))
 	verify {
-		assert_last_event::<T, I>(Event::Burned { collection, item, owner: caller }.into());
+		assert_last_event::<T, I>(
+			Event::Burned {
+				collection,
+				item,
+				owner: caller.clone(),
+				burner: Some(caller),
+				via_sink: false,
+			}.into(),
+		);
 	}
 
 	transfer {
@@ -200,6 +257,40 @@ benchmarks_instance_pallet! {
 
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
+		let membership = T::Helper::collection(1);
+		Uniques::<T, I>::force_create(
+			SystemOrigin::Root.into(),
+			membership,
+			caller_lookup,
+			true,
+		)?;
+		Uniques::<T, I>::mint(
+			SystemOrigin::Signed(caller.clone()).into(),
+			membership,
+			T::Helper::item(0),
+			target_lookup.clone(),
+		)?;
+		Uniques::<T, I>::do_set_collection_config(
+			collection,
+			CollectionConfig {
+				settings: CollectionSettings(
+					CollectionSetting::ForbidSelfTransfer |
+						CollectionSetting::RequireFirstTransferApproval,
+				),
+				required_membership: Some(membership),
+				max_distinct_owners: Some(u32::MAX),
+				holding_period: Some(One::one()),
+				..Default::default()
+			},
+			None,
+		)?;
+		Uniques::<T, I>::approve_first_transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+		)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + One::one());
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, target_lookup)
 	verify {
 		assert_last_event::<T, I>(Event::Transferred { collection, item, from: caller, to: target }.into());
@@ -332,9 +423,10 @@ benchmarks_instance_pallet! {
 
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
-	}: _(SystemOrigin::Signed(caller), collection, item, data.clone(), false)
+		let storage_proof = Some(data.clone());
+	}: _(SystemOrigin::Signed(caller), collection, item, data.clone(), false, storage_proof.clone())
 	verify {
-		assert_last_event::<T, I>(Event::MetadataSet { collection, item, data, is_frozen: false }.into());
+		assert_last_event::<T, I>(Event::MetadataSet { collection, item, data, is_frozen: false, storage_proof }.into());
 	}
 
 	clear_metadata {
@@ -368,9 +460,16 @@ benchmarks_instance_pallet! {
 		let (item, ..) = mint_item::<T, I>(0);
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
-	}: _(SystemOrigin::Signed(caller.clone()), collection, item, delegate_lookup)
+		let deadline = T::BlockNumber::max_value();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, delegate_lookup, Some(deadline))
 	verify {
-		assert_last_event::<T, I>(Event::ApprovedTransfer { collection, item, owner: caller, delegate }.into());
+		assert_last_event::<T, I>(Event::ApprovedTransfer {
+			collection,
+			item,
+			owner: caller,
+			delegate,
+			deadline: Some(deadline),
+		}.into());
 	}
 
 	cancel_approval {
@@ -379,7 +478,7 @@ benchmarks_instance_pallet! {
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
 		let origin = SystemOrigin::Signed(caller.clone()).into();
-		Uniques::<T, I>::approve_transfer(origin, collection, item, delegate_lookup.clone())?;
+		Uniques::<T, I>::approve_transfer(origin, collection, item, delegate_lookup.clone(), None)?;
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(delegate_lookup))
 	verify {
 		assert_last_event::<T, I>(Event::ApprovalCancelled { collection, item, owner: caller, delegate }.into());
@@ -443,5 +542,483 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	set_collection_config {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::ForbidSelfTransfer.into()),
+			..Default::default()
+		};
+	}: _(SystemOrigin::Signed(caller), collection, config.clone())
+	verify {
+		assert_last_event::<T, I>(Event::CollectionConfigChanged { collection, config }.into());
+	}
+
+	open_escrow {
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		let price = ItemPrice::<T, I>::from(100u32);
+		let expiry = frame_system::Pallet::<T>::block_number() + T::EscrowTimeout::get();
+	}: _(SystemOrigin::Signed(seller.clone()), collection, item, price, buyer_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::EscrowOpened {
+			collection,
+			item,
+			seller,
+			buyer,
+			price,
+			expiry,
+		}.into());
+	}
+
+	fund_escrow {
+		let (collection, ..) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (_, buyer, price) = open_item_escrow::<T, I>(item);
+	}: _(SystemOrigin::Signed(buyer.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::EscrowFunded { collection, item, buyer, price }.into());
+	}
+
+	confirm_escrow {
+		let (collection, ..) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (seller, buyer, price) = open_item_escrow::<T, I>(item);
+		let balance = DepositBalanceOf::<T, I>::max_value() / 2u32.into();
+		T::Currency::make_free_balance_be(&seller, balance);
+		Uniques::<T, I>::fund_escrow(SystemOrigin::Signed(buyer.clone()).into(), collection, item)?;
+		let origin = SystemOrigin::Signed(seller.clone()).into();
+		Uniques::<T, I>::confirm_escrow(origin, collection, item)?;
+	}: _(SystemOrigin::Signed(buyer.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::EscrowSettled {
+			collection,
+			item,
+			seller,
+			buyer,
+			price,
+		}.into());
+	}
+
+	cancel_escrow {
+		let (collection, ..) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let (seller, buyer, _) = open_item_escrow::<T, I>(item);
+		Uniques::<T, I>::fund_escrow(SystemOrigin::Signed(buyer).into(), collection, item)?;
+	}: _(SystemOrigin::Signed(seller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::EscrowCancelled { collection, item }.into());
+	}
+
+	expire_escrows {
+		let n in 0 .. T::MaxEscrowsPerBlock::get();
+		let (collection, ..) = create_collection::<T, I>();
+		for i in 0..n {
+			let (item, ..) = mint_item::<T, I>(i as u16);
+			let (_, buyer, _) = open_item_escrow::<T, I>(item);
+			Uniques::<T, I>::fund_escrow(SystemOrigin::Signed(buyer).into(), collection, item)?;
+		}
+		let expiry = frame_system::Pallet::<T>::block_number() + T::EscrowTimeout::get();
+	}: {
+		Uniques::<T, I>::expire_escrows(expiry);
+	}
+	verify {
+		assert!(EscrowExpiries::<T, I>::get(expiry).is_empty());
+		assert_eq!(EscrowsOf::<T, I>::iter_prefix(collection).count(), 0);
+	}
+
+	set_role_many {
+		let n in 1 .. T::MaxRoleAssignments::get();
+		let (_, caller, caller_lookup) = create_collection::<T, I>();
+		let freezer: T::AccountId = account("freezer", 0, SEED);
+		let freezer_lookup = T::Lookup::unlookup(freezer.clone());
+		let mut assignments = vec![];
+		for i in 0..n {
+			let collection = T::Helper::collection(i as u16);
+			if i > 0 {
+				Uniques::<T, I>::force_create(
+					SystemOrigin::Root.into(),
+					collection,
+					caller_lookup.clone(),
+					false,
+				)?;
+			}
+			assignments.push((collection, CollectionRole::Freezer, freezer_lookup.clone()));
+		}
+		let assignments: BoundedVec<_, _> = assignments.try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), assignments)
+	verify {
+		let assignments = (0..n)
+			.map(|i| (T::Helper::collection(i as u16), CollectionRole::Freezer, freezer.clone()))
+			.collect();
+		assert_last_event::<T, I>(Event::RolesSet { owner: caller, assignments }.into());
+	}
+
+	refresh_item_deposit {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		Uniques::<T, I>::force_item_status(
+			SystemOrigin::Root.into(),
+			collection,
+			caller_lookup.clone(),
+			caller_lookup.clone(),
+			caller_lookup.clone(),
+			caller_lookup,
+			true,
+			false,
+		)?;
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemDepositRefreshed {
+			collection,
+			item,
+			old: T::ItemDeposit::get(),
+			new: Zero::zero(),
+		}.into());
+	}
+
+	mint_next {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		Uniques::<T, I>::do_set_collection_config(
+			collection,
+			CollectionConfig {
+				settings: CollectionSettings(CollectionSetting::SequentialIds.into()),
+				..Default::default()
+			},
+			None,
+		)?;
+		let item = T::ItemId::initial_value();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, caller_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::Issued { collection, item, owner: caller }.into());
+	}
+
+	release_mint_stakes {
+		let n in 0 .. 100;
+		let release_at = frame_system::Pallet::<T>::block_number() + T::MintStakeCooldown::get();
+		let stake = T::Currency::minimum_balance();
+		for i in 0..n {
+			let minter: T::AccountId = account("minter", i, SEED);
+			T::Currency::make_free_balance_be(&minter, DepositBalanceOf::<T, I>::max_value());
+			T::Currency::reserve(&minter, stake)?;
+			MintStakeOf::<T, I>::insert(release_at, &minter, stake);
+		}
+	}: {
+		Uniques::<T, I>::release_mint_stakes(release_at);
+	}
+	verify {
+		assert_eq!(MintStakeOf::<T, I>::iter_prefix(release_at).count(), 0);
+	}
+
+	set_item_name {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		Uniques::<T, I>::do_set_collection_config(
+			collection,
+			CollectionConfig {
+				settings: CollectionSettings(CollectionSetting::UniqueItemNames.into()),
+				..Default::default()
+			},
+			None,
+		)?;
+		Uniques::<T, I>::do_set_item_name(collection, item, Default::default(), None)?;
+		let name: BoundedVec<_, _> =
+			vec![0u8; T::ItemNameLimit::get() as usize].try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller), collection, item, name.clone())
+	verify {
+		assert_last_event::<T, I>(Event::ItemNameSet { collection, item, name }.into());
+	}
+
+	lock_collection_recurring {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let duration = T::BlockNumber::one();
+		let period = duration + duration;
+	}: _(SystemOrigin::Signed(caller), collection, duration, period)
+	verify {
+		assert_last_event::<T, I>(Event::RecurringLockSet { collection, duration, period }.into());
+	}
+
+	cancel_recurring_lock {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let duration = T::BlockNumber::one();
+		Uniques::<T, I>::do_lock_collection_recurring(
+			collection,
+			duration,
+			duration + duration,
+			caller.clone(),
+		)?;
+	}: _(SystemOrigin::Signed(caller), collection)
+	verify {
+		assert_last_event::<T, I>(Event::RecurringLockCancelled { collection }.into());
+	}
+
+	cycle_recurring_locks {
+		let n in 0 .. T::MaxRecurringLocks::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let duration = T::BlockNumber::one();
+		for i in 0..n {
+			let collection = T::Helper::collection(i as u16);
+			Uniques::<T, I>::force_create(
+				SystemOrigin::Root.into(),
+				collection,
+				caller_lookup.clone(),
+				false,
+			)?;
+			Uniques::<T, I>::do_lock_collection_recurring(
+				collection,
+				duration,
+				duration + duration,
+				caller.clone(),
+			)?;
+		}
+		let now = frame_system::Pallet::<T>::block_number() + duration;
+	}: {
+		Uniques::<T, I>::cycle_recurring_locks(now);
+	}
+	verify {
+		assert!(RecurringLockTransitions::<T, I>::get(now).is_empty());
+		assert_eq!(RecurringLockTransitions::<T, I>::get(now + duration).len() as u32, n);
+	}
+
+	freeze_collection_content {
+		let (collection, caller, _) = create_collection::<T, I>();
+	}: _(SystemOrigin::Signed(caller), collection)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionContentFrozen { collection }.into());
+	}
+
+	wrap_item {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (parent, ..) = mint_item::<T, I>(0);
+		let (child, ..) = mint_item::<T, I>(1);
+	}: _(SystemOrigin::Signed(caller), (collection, parent), (collection, child))
+	verify {
+		assert_last_event::<T, I>(Event::ItemWrapped {
+			collection,
+			item: child,
+			parent_collection: collection,
+			parent_item: parent,
+		}.into());
+	}
+
+	unwrap_item {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (parent, ..) = mint_item::<T, I>(0);
+		let (child, ..) = mint_item::<T, I>(1);
+		Uniques::<T, I>::do_wrap_item((collection, parent), (collection, child), caller.clone())?;
+	}: _(SystemOrigin::Signed(caller.clone()), (collection, child))
+	verify {
+		assert_last_event::<T, I>(Event::ItemUnwrapped {
+			collection,
+			item: child,
+			owner: caller,
+		}.into());
+	}
+
+	approve_first_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::FirstTransferApproved { collection, item }.into());
+	}
+
+	set_unrevealed_metadata {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller), collection, data.clone())
+	verify {
+		assert_last_event::<T, I>(Event::UnrevealedMetadataSet { collection, data }.into());
+	}
+
+	reveal_collection {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+		Uniques::<T, I>::do_set_unrevealed_metadata(collection, data.clone(), None)?;
+		Uniques::<T, I>::do_commit_reveal_hash(collection, T::Hashing::hash(&data), None)?;
+	}: _(SystemOrigin::Signed(caller), collection, Some(data))
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRevealed { collection }.into());
+	}
+
+	commit_reveal_hash {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let hash = T::Hashing::hash(&[0u8; 32]);
+	}: _(SystemOrigin::Signed(caller), collection, hash)
+	verify {
+		assert_last_event::<T, I>(Event::RevealCommitted { collection, hash }.into());
+	}
+
+	set_marketplace_policy {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let mut allowed = BoundedBTreeSet::new();
+		for i in 0..T::MaxMarketplaces::get() {
+			allowed.try_insert(account("marketplace", i, SEED)).unwrap();
+		}
+		let policy = MarketplacePolicy::Allowlist(allowed);
+	}: _(SystemOrigin::Signed(caller), collection, policy.clone())
+	verify {
+		assert_last_event::<T, I>(Event::MarketplacePolicySet { collection, policy }.into());
+	}
+
+	force_transfer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		Uniques::<T, I>::freeze(SystemOrigin::Signed(caller).into(), collection, item)?;
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target);
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::force_transfer { collection, item, dest: target_lookup };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::ItemForceTransferred { collection, item }.into());
+	}
+
+	make_item_soulbound {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemMadeSoulbound { collection, item }.into());
+	}
+
+	mint_and_list {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let item = T::Helper::item(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		let price = ItemPrice::<T, I>::from(100u32);
+	}: _(SystemOrigin::Signed(caller), collection, item, price, Some(buyer_lookup))
+	verify {
+		assert_last_event::<T, I>(Event::ItemPriceSet {
+			collection,
+			item,
+			price,
+			whitelisted_buyer: Some(buyer),
+		}.into());
+	}
+
+	transfer_many_to {
+		let n in 1 .. T::MaxItemsPerTransfer::get();
+		let (collection, caller, _) = create_collection::<T, I>();
+		let items: Vec<_> = (0..n).map(|i| mint_item::<T, I>(i as u16).0).collect();
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let items: BoundedVec<_, _> = items.try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller), collection, items.clone(), target_lookup, false)
+	verify {
+		assert_last_event::<T, I>(Event::ItemsTransferred { collection, items, dest: target }.into());
+	}
+
+	set_collection_bond {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let amount = T::Currency::minimum_balance();
+	}: _(SystemOrigin::Signed(caller), collection, amount)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionBondSet { collection, amount }.into());
+	}
+
+	slash_collection_bond {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let amount = T::Currency::minimum_balance();
+		Uniques::<T, I>::do_set_collection_bond(collection, amount, caller)?;
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T, I>::slash_collection_bond { collection, amount };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::CollectionBondSlashed { collection, amount }.into());
+	}
+
+	release_collection_bond {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let amount = T::Currency::minimum_balance();
+		Uniques::<T, I>::do_set_collection_bond(collection, amount, caller.clone())?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + T::BondReleaseCooldown::get());
+	}: _(SystemOrigin::Signed(caller), collection)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionBondReleased { collection, amount }.into());
+	}
+
+	transfer_as_delegate {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::approve_transfer(origin, collection, item, delegate_lookup, None)?;
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target);
+	}: _(SystemOrigin::Signed(delegate.clone()), collection, item, target_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::ApprovalConsumed { collection, item, owner: caller, delegate }.into());
+	}
+
+	set_burn_requirement {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let requirement = Some(BurnRequirement {
+			key: vec![0u8; T::KeyLimit::get() as usize].try_into().unwrap(),
+			value: vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap(),
+		});
+	}: _(SystemOrigin::Signed(caller), collection, requirement.clone())
+	verify {
+		assert_last_event::<T, I>(Event::BurnRequirementSet { collection, requirement }.into());
+	}
+
+	clear_all_collection_approvals {
+		let n in 0 .. 1_000;
+		let (collection, caller, _) = create_collection::<T, I>();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate);
+		for i in 0..n {
+			let (item, ..) = mint_item::<T, I>(i as u16);
+			let origin = SystemOrigin::Signed(caller.clone()).into();
+			Uniques::<T, I>::approve_transfer(origin, collection, item, delegate_lookup.clone(), None)?;
+		}
+	}: _(SystemOrigin::Signed(caller), collection, n, None)
+	verify {
+		assert_last_event::<T, I>(Event::AllApprovalsCleared { collection, cleared: n, resume_from: None }.into());
+	}
+
+	recover_item {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		Uniques::<T, I>::freeze(SystemOrigin::Signed(caller).into(), collection, item)?;
+		let recovery: T::AccountId = account("recovery", 0, SEED);
+		Uniques::<T, I>::do_set_collection_config(
+			collection,
+			CollectionConfig { recovery_account: Some(recovery.clone()), ..Default::default() },
+			None,
+		)?;
+	}: _(SystemOrigin::Signed(recovery), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemRecovered { collection, item }.into());
+	}
+
+	approve_unlock {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let issuer: T::AccountId = account("issuer", 0, SEED);
+		Uniques::<T, I>::set_team(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::Lookup::unlookup(issuer.clone()),
+			caller_lookup.clone(),
+			caller_lookup,
+		)?;
+		Uniques::<T, I>::freeze(SystemOrigin::Signed(caller.clone()).into(), collection, item)?;
+		Uniques::<T, I>::do_set_collection_config(
+			collection,
+			CollectionConfig { unlock_threshold: 2, ..Default::default() },
+			None,
+		)?;
+		Uniques::<T, I>::approve_unlock(SystemOrigin::Signed(issuer).into(), collection, item)?;
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemTransferUnlocked { collection, item }.into());
+	}
+
 	impl_benchmark_test_suite!(Uniques, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -0,0 +1,91 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functionality for the bond collection owners post against the takedown of their content.
+
+use super::*;
+use frame_support::{
+	ensure,
+	traits::{Get, Imbalance},
+};
+use sp_runtime::DispatchResult;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_set_collection_bond(
+		collection: T::CollectionId,
+		amount: DepositBalanceOf<T, I>,
+		check_owner: T::AccountId,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(Self::is_collection_owner(collection, &check_owner), Error::<T, I>::NoPermission);
+
+		CollectionBondOf::<T, I>::try_mutate(&collection, |maybe_bond| -> DispatchResult {
+			let old = maybe_bond.as_ref().map_or(Zero::zero(), |bond| bond.amount);
+			ensure!(amount >= old, Error::<T, I>::BondTooLow);
+			T::Currency::reserve(&details.owner, amount - old)?;
+			*maybe_bond = Some(CollectionBond {
+				amount,
+				clean_since: frame_system::Pallet::<T>::block_number(),
+			});
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::CollectionBondSet { collection, amount });
+		Ok(())
+	}
+
+	pub fn do_slash_collection_bond(
+		collection: T::CollectionId,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let mut bond = CollectionBondOf::<T, I>::get(&collection).ok_or(Error::<T, I>::NoBond)?;
+
+		let (imbalance, _) = T::Currency::slash_reserved(&details.owner, amount.min(bond.amount));
+		let slashed = imbalance.peek();
+		T::BondSlashed::on_unbalanced(imbalance);
+		bond.amount.saturating_reduce(slashed);
+		bond.clean_since = frame_system::Pallet::<T>::block_number();
+		CollectionBondOf::<T, I>::insert(&collection, bond);
+
+		Self::deposit_event(Event::CollectionBondSlashed { collection, amount: slashed });
+		Ok(())
+	}
+
+	pub fn do_release_collection_bond(
+		collection: T::CollectionId,
+		check_owner: T::AccountId,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(Self::is_collection_owner(collection, &check_owner), Error::<T, I>::NoPermission);
+		let bond = CollectionBondOf::<T, I>::get(&collection).ok_or(Error::<T, I>::NoBond)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >=
+				bond.clean_since.saturating_add(T::BondReleaseCooldown::get()),
+			Error::<T, I>::BondLocked
+		);
+
+		T::Currency::unreserve(&details.owner, bond.amount);
+		CollectionBondOf::<T, I>::remove(&collection);
+
+		Self::deposit_event(Event::CollectionBondReleased { collection, amount: bond.amount });
+		Ok(())
+	}
+}
@@ -0,0 +1,227 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functionality for selling items through an escrow held by the pallet.
+
+use super::*;
+use frame_support::{
+	ensure,
+	traits::{BalanceStatus, Get},
+	weights::Weight,
+};
+use sp_runtime::{traits::AccountIdConversion, DispatchResult};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The account holding the items of all open escrows.
+	pub fn escrow_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	pub fn do_open_escrow(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		seller: T::AccountId,
+		price: ItemPrice<T, I>,
+		buyer: T::AccountId,
+	) -> DispatchResult {
+		ensure!(seller != buyer, Error::<T, I>::NoPermission);
+
+		let (_, details) = Self::ensure_item_movable(collection, item)?;
+		ensure!(details.owner == seller, Error::<T, I>::NoPermission);
+		// the escrow account only takes custody of the item, so the transfer restrictions of the
+		// collection apply to the sale from the seller to the buyer.
+		let config = CollectionConfigOf::<T, I>::get(&collection);
+		Self::ensure_receiver_allowed(&collection, &item, &config, &seller, Some(&seller), &buyer)?;
+		Self::move_into_custody(collection, item, details, Self::escrow_account());
+
+		let expiry =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::EscrowTimeout::get());
+		EscrowExpiries::<T, I>::try_append(expiry, (collection, item))
+			.map_err(|_| Error::<T, I>::TooManyEscrows)?;
+		EscrowsOf::<T, I>::insert(
+			&collection,
+			&item,
+			EscrowDetails {
+				seller: seller.clone(),
+				buyer: buyer.clone(),
+				price,
+				funded: false,
+				seller_confirmed: false,
+				buyer_confirmed: false,
+				expiry,
+			},
+		);
+
+		Self::deposit_event(Event::EscrowOpened { collection, item, seller, buyer, price, expiry });
+		Ok(())
+	}
+
+	pub fn do_fund_escrow(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		buyer: T::AccountId,
+	) -> DispatchResult {
+		EscrowsOf::<T, I>::try_mutate(&collection, &item, |maybe_escrow| {
+			let escrow = maybe_escrow.as_mut().ok_or(Error::<T, I>::NoEscrow)?;
+			ensure!(escrow.buyer == buyer, Error::<T, I>::NoPermission);
+			ensure!(!escrow.funded, Error::<T, I>::AlreadyFunded);
+
+			T::Currency::reserve(&buyer, escrow.price)?;
+			escrow.funded = true;
+
+			Self::deposit_event(Event::EscrowFunded {
+				collection,
+				item,
+				buyer,
+				price: escrow.price,
+			});
+			Ok(())
+		})
+	}
+
+	pub fn do_confirm_escrow(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		who: T::AccountId,
+	) -> DispatchResult {
+		let mut escrow =
+			EscrowsOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::NoEscrow)?;
+		if who == escrow.seller {
+			escrow.seller_confirmed = true;
+		} else if who == escrow.buyer {
+			ensure!(escrow.funded, Error::<T, I>::NotFunded);
+			escrow.buyer_confirmed = true;
+		} else {
+			return Err(Error::<T, I>::NoPermission.into())
+		}
+		Self::deposit_event(Event::EscrowConfirmed { collection, item, who });
+
+		if escrow.seller_confirmed && escrow.buyer_confirmed {
+			Self::settle_escrow(collection, item, escrow)
+		} else {
+			EscrowsOf::<T, I>::insert(&collection, &item, escrow);
+			Ok(())
+		}
+	}
+
+	pub fn do_cancel_escrow(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_party: Option<T::AccountId>,
+	) -> DispatchResult {
+		let escrow = EscrowsOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::NoEscrow)?;
+		if let Some(check_party) = maybe_check_party {
+			ensure!(
+				check_party == escrow.seller || check_party == escrow.buyer,
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		Self::remove_escrow_expiry(escrow.expiry, collection, item);
+		Self::unwind_escrow(collection, item, escrow);
+
+		Self::deposit_event(Event::EscrowCancelled { collection, item });
+		Ok(())
+	}
+
+	/// Unwind all escrows which expire at block `now`.
+	pub(crate) fn expire_escrows(now: T::BlockNumber) -> Weight {
+		let expiring = EscrowExpiries::<T, I>::take(now);
+		let count = expiring.len() as u32;
+		for (collection, item) in expiring {
+			if let Some(escrow) = EscrowsOf::<T, I>::get(&collection, &item) {
+				Self::unwind_escrow(collection, item, escrow);
+				Self::deposit_event(Event::EscrowExpired { collection, item });
+			}
+		}
+		T::WeightInfo::expire_escrows(count)
+	}
+
+	/// Pay the seller out of the buyer's reserve and hand the item to the buyer.
+	fn settle_escrow(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		escrow: EscrowDetailsFor<T, I>,
+	) -> DispatchResult {
+		EscrowsOf::<T, I>::remove(&collection, &item);
+		Self::remove_escrow_expiry(escrow.expiry, collection, item);
+
+		let (_, details) = Self::ensure_item_movable(collection, item)?;
+		let config = CollectionConfigOf::<T, I>::get(&collection);
+		Self::ensure_receiver_allowed(
+			&collection,
+			&item,
+			&config,
+			&escrow.seller,
+			None,
+			&escrow.buyer,
+		)?;
+
+		let unpaid = T::Currency::repatriate_reserved(
+			&escrow.buyer,
+			&escrow.seller,
+			escrow.price,
+			BalanceStatus::Free,
+		)?;
+		ensure!(unpaid.is_zero(), Error::<T, I>::NotFunded);
+
+		Self::move_item(collection, item, details, escrow.buyer.clone());
+		Self::freeze_after_transfer(collection, item);
+
+		Self::deposit_event(Event::EscrowSettled {
+			collection,
+			item,
+			seller: escrow.seller,
+			buyer: escrow.buyer,
+			price: escrow.price,
+		});
+		Ok(())
+	}
+
+	/// Refund the buyer and give the item back to the seller.
+	///
+	/// The item is moved regardless of whether it or its collection is frozen, since it only
+	/// returns to where it was before the escrow was opened.
+	fn unwind_escrow(collection: T::CollectionId, item: T::ItemId, escrow: EscrowDetailsFor<T, I>) {
+		EscrowsOf::<T, I>::remove(&collection, &item);
+		if escrow.funded {
+			T::Currency::unreserve(&escrow.buyer, escrow.price);
+		}
+
+		Item::<T, I>::mutate(&collection, &item, |maybe_details| {
+			if let Some(details) = maybe_details {
+				Self::remove_account_item(&details.owner, collection, item);
+				Self::insert_account_item(&escrow.seller, collection, item);
+				let from = sp_std::mem::replace(&mut details.owner, escrow.seller.clone());
+				if !Self::is_event_muted(&collection, EventCategory::Transfers) {
+					Self::deposit_event(Event::Transferred {
+						collection,
+						item,
+						from,
+						to: escrow.seller,
+					});
+				}
+			}
+		});
+	}
+
+	fn remove_escrow_expiry(expiry: T::BlockNumber, collection: T::CollectionId, item: T::ItemId) {
+		EscrowExpiries::<T, I>::mutate(expiry, |expiring| {
+			expiring.retain(|entry| entry != &(collection, item))
+		});
+	}
+}
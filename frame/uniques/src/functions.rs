@@ -20,9 +20,13 @@
 use super::*;
 use frame_support::{
 	ensure,
-	traits::{ExistenceRequirement, Get},
+	traits::{ExistenceRequirement, Get, WithdrawReasons},
+	weights::Weight,
+};
+use sp_runtime::{
+	traits::{Hash, One},
+	DispatchError, DispatchResult,
 };
-use sp_runtime::{DispatchError, DispatchResult};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub fn do_transfer(
@@ -34,35 +38,495 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			&mut ItemDetailsFor<T, I>,
 		) -> DispatchResult,
 	) -> DispatchResult {
+		let (collection_details, mut details) = Self::ensure_item_movable(collection, item)?;
+		with_details(&collection_details, &mut details)?;
+
+		let config = CollectionConfigOf::<T, I>::get(&collection);
+		if config.burn_on_transfer_to.as_ref() == Some(&dest) {
+			let owner = details.owner;
+			// taking the item out of circulation is subject to the policy like any transfer.
+			T::TransferPolicy::can_transfer(&collection, &item, &owner, &dest)?;
+			return Self::burn_item(collection, item, Some(owner), true, |_, _| Ok(()))
+		}
+		let owner = details.owner.clone();
+		Self::ensure_receiver_allowed(&collection, &item, &config, &owner, Some(&owner), &dest)?;
+
+		Self::move_item(collection, item, details, dest);
+		Ok(())
+	}
+
+	/// Ensure that neither `item` nor its collection are frozen, locked, escrowed or wrapped.
+	///
+	/// Returns the details of the collection and the item.
+	pub(crate) fn ensure_item_movable(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> Result<(CollectionDetailsFor<T, I>, ItemDetailsFor<T, I>), DispatchError> {
 		let collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 		ensure!(!collection_details.is_frozen, Error::<T, I>::Frozen);
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::Locked);
+		ensure!(!EscrowsOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::InEscrow);
+		// wrapped items only leave the wrap account through `unwrap_item`.
+		ensure!(!WrappedItemsOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::Wrapped);
 
-		let mut details =
+		let details =
 			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
 		ensure!(!details.is_frozen, Error::<T, I>::Frozen);
-		with_details(&collection_details, &mut details)?;
+		Ok((collection_details, details))
+	}
+
+	/// Ensure that the transfer restrictions of `config` allow `item` to pass from `from` to
+	/// `dest`.
+	///
+	/// `maybe_source` is the account the item leaves, if it still holds it, which frees its slot
+	/// among the distinct owners of the collection.
+	pub(crate) fn ensure_receiver_allowed(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		config: &CollectionConfigFor<T, I>,
+		from: &T::AccountId,
+		maybe_source: Option<&T::AccountId>,
+		dest: &T::AccountId,
+	) -> DispatchResult {
+		Self::ensure_transfer_allowed(config, from, dest)?;
+		Self::ensure_owner_count_allowed(collection, config, maybe_source, dest)?;
+		Self::ensure_first_transfer_approved(config, collection, item)?;
+		Self::ensure_holding_period_passed(config, collection, item)?;
+		T::TransferPolicy::can_transfer(collection, item, from, dest)
+	}
+
+	/// Transfer each of `items` to `dest` on behalf of `sender`, as `transfer` does.
+	///
+	/// Fails on the first item which can't be transferred, unless `force` is set, in which case
+	/// that item is skipped. A failing transfer changes nothing, so skipping it is safe.
+	pub fn do_transfer_many_to(
+		collection: T::CollectionId,
+		items: BoundedVec<T::ItemId, T::MaxItemsPerTransfer>,
+		dest: T::AccountId,
+		sender: T::AccountId,
+		force: bool,
+	) -> DispatchResult {
+		let mut transferred = BoundedVec::<T::ItemId, T::MaxItemsPerTransfer>::default();
+		for item in items {
+			let result =
+				Self::do_transfer(collection, item, dest.clone(), |collection_details, details| {
+					if details.owner != sender && collection_details.admin != sender {
+						Self::check_approval(details, &sender)?;
+					}
+					Ok(())
+				});
+			match result {
+				Ok(()) => {
+					Self::freeze_after_transfer(collection, item);
+					// can't overflow, as there are no more items than fit into `items`.
+					let _ = transferred.try_push(item);
+				},
+				Err(_) if force => continue,
+				Err(e) => return Err(e),
+			}
+		}
 
-		Account::<T, I>::remove((&details.owner, &collection, &item));
-		Account::<T, I>::insert((&dest, &collection, &item), ());
+		Self::deposit_event(Event::ItemsTransferred { collection, items: transferred, dest });
+		Ok(())
+	}
+
+	/// Transfer `item` to `dest` on behalf of its approved `delegate`, using up the approval.
+	///
+	/// All approvals are cleared by the transfer anyway, so the approval can't be used again.
+	pub fn do_transfer_as_delegate(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+		delegate: T::AccountId,
+	) -> DispatchResult {
+		let mut owner = None;
+		Self::do_transfer(collection, item, dest, |_, details| {
+			ensure!(details.approvals.contains_key(&delegate), Error::<T, I>::NotApproved);
+			Self::check_approval(details, &delegate)?;
+			owner = Some(details.owner.clone());
+			Ok(())
+		})?;
+		Self::freeze_after_transfer(collection, item);
+
+		if let Some(owner) = owner {
+			Self::deposit_event(Event::ApprovalConsumed { collection, item, owner, delegate });
+		}
+		Ok(())
+	}
+
+	/// Transfer an item to `dest` on behalf of the `ForceOrigin`, regardless of freezes, locks,
+	/// approvals and the transfer restrictions of the collection.
+	///
+	/// Escrowed and wrapped items still can't be moved, as that would leave their escrow or
+	/// wrap dangling.
+	pub fn do_force_transfer(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+	) -> DispatchResult {
+		ensure!(!EscrowsOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::InEscrow);
+		ensure!(!WrappedItemsOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::Wrapped);
+		let details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
+
+		Self::move_item(collection, item, details, dest);
+		Self::deposit_event(Event::ItemForceTransferred { collection, item });
+		Ok(())
+	}
+
+	/// Move an item to the recovery account of its collection on behalf of `recoverer`, as
+	/// `do_force_transfer` does.
+	pub fn do_recover_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		recoverer: T::AccountId,
+	) -> DispatchResult {
+		let config = CollectionConfigOf::<T, I>::get(&collection);
+		ensure!(config.recovery_account.as_ref() == Some(&recoverer), Error::<T, I>::NoPermission);
+		ensure!(!EscrowsOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::InEscrow);
+		ensure!(!WrappedItemsOf::<T, I>::contains_key(&collection, &item), Error::<T, I>::Wrapped);
+		let details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
+
+		Self::move_item(collection, item, details, recoverer);
+		Self::deposit_event(Event::ItemRecovered { collection, item });
+		Ok(())
+	}
+
+	/// Move an item with `details` to `dest`, clearing its approvals and listing.
+	pub(crate) fn move_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		details: ItemDetailsFor<T, I>,
+		dest: T::AccountId,
+	) {
+		Self::move_into_custody(collection, item, details, dest);
+		TransferCountOf::<T, I>::mutate(&collection, &item, |count| {
+			*count = count.saturating_add(1)
+		});
+		LastTransferOf::<T, I>::insert(
+			&collection,
+			&item,
+			frame_system::Pallet::<T>::block_number(),
+		);
+	}
+
+	/// Move an item with `details` to `dest` as `move_item` does, but without counting it as a
+	/// transfer, e.g. into and out of the custody of the escrow account.
+	pub(crate) fn move_into_custody(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		mut details: ItemDetailsFor<T, I>,
+		dest: T::AccountId,
+	) {
+		Self::remove_account_item(&details.owner, collection, item);
+		Self::insert_account_item(&dest, collection, item);
 		let origin = details.owner;
 		details.owner = dest;
 
-		// The approved account has to be reset to None, because otherwise pre-approve attack would
-		// be possible, where the owner can approve his second account before making the transaction
-		// and then claiming the item back.
-		details.approved = None;
+		// The approvals have to be reset, because otherwise pre-approve attack would be possible,
+		// where the owner can approve his second account before making the transaction and then
+		// claiming the item back.
+		details.approvals.clear();
 
 		Item::<T, I>::insert(&collection, &item, &details);
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 
-		Self::deposit_event(Event::Transferred {
+		if !Self::is_event_muted(&collection, EventCategory::Transfers) {
+			Self::deposit_event(Event::Transferred {
+				collection,
+				item,
+				from: origin,
+				to: details.owner,
+			});
+		}
+	}
+
+	/// Disallow further unprivileged transfers of an item.
+	///
+	/// Returns the weight consumed, `T::WeightInfo::freeze()`, for callers composing this into
+	/// larger calls.
+	pub fn do_freeze_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_freezer: Option<T::AccountId>,
+	) -> Result<Weight, DispatchError> {
+		let mut details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_freezer) = maybe_check_freezer {
+			ensure!(collection_details.freezer == check_freezer, Error::<T, I>::NoPermission);
+		}
+
+		details.is_frozen = true;
+		Item::<T, I>::insert(&collection, &item, &details);
+		UnlockApprovalsOf::<T, I>::remove(&collection, &item);
+
+		Self::deposit_event(Event::<T, I>::Frozen { collection, item });
+		Ok(T::WeightInfo::freeze())
+	}
+
+	/// Re-allow unprivileged transfers of an item.
+	///
+	/// Returns the weight consumed, `T::WeightInfo::thaw()`.
+	pub fn do_thaw_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_admin: Option<T::AccountId>,
+	) -> Result<Weight, DispatchError> {
+		let mut details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
+		let collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_admin) = maybe_check_admin {
+			ensure!(collection_details.admin == check_admin, Error::<T, I>::NoPermission);
+			let config = CollectionConfigOf::<T, I>::get(&collection);
+			ensure!(config.unlock_threshold <= 1, Error::<T, I>::UnlockNotApproved);
+		}
+		ensure!(
+			!SoulboundItemsOf::<T, I>::contains_key(&collection, &item),
+			Error::<T, I>::ItemSoulbound
+		);
+
+		details.is_frozen = false;
+		Item::<T, I>::insert(&collection, &item, &details);
+		UnlockApprovalsOf::<T, I>::remove(&collection, &item);
+
+		Self::deposit_event(Event::<T, I>::Thawed { collection, item });
+		Ok(T::WeightInfo::thaw())
+	}
+
+	/// Record the approval of `approver` to thaw a frozen item, thawing it once the
+	/// `unlock_threshold` of its collection is met.
+	pub fn do_approve_unlock(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		approver: T::AccountId,
+	) -> DispatchResult {
+		let details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(details.is_frozen, Error::<T, I>::NotFrozen);
+		let collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let is_role_holder = [
+			&collection_details.owner,
+			&collection_details.issuer,
+			&collection_details.admin,
+			&collection_details.freezer,
+		]
+		.contains(&&approver);
+		ensure!(is_role_holder, Error::<T, I>::NoPermission);
+		// up to one approval, the Admin thaws items alone through `do_thaw_item`.
+		let threshold = CollectionConfigOf::<T, I>::get(&collection).unlock_threshold;
+		ensure!(threshold > 1, Error::<T, I>::NoUnlockThreshold);
+
+		let mut approvals = UnlockApprovalsOf::<T, I>::get(&collection, &item);
+		// can't be full, as there are no more role holders than fit.
+		let added = approvals
+			.try_insert(approver.clone())
+			.map_err(|_| Error::<T, I>::NoPermission)?;
+		ensure!(added, Error::<T, I>::UnlockAlreadyApproved);
+		Self::deposit_event(Event::UnlockApprovalAdded {
 			collection,
 			item,
-			from: origin,
-			to: details.owner,
+			approver,
+			approvals: approvals.len() as u32,
 		});
+
+		if approvals.len() >= threshold as usize {
+			Self::do_thaw_item(collection, item, None)?;
+			Self::deposit_event(Event::ItemTransferUnlocked { collection, item });
+		} else {
+			UnlockApprovalsOf::<T, I>::insert(&collection, &item, approvals);
+		}
+		Ok(())
+	}
+
+	/// Freeze an item for good, so that `do_thaw_item` refuses to thaw it.
+	pub fn do_make_item_soulbound(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_freezer: Option<T::AccountId>,
+	) -> DispatchResult {
+		Self::do_freeze_item(collection, item, maybe_check_freezer)?;
+		SoulboundItemsOf::<T, I>::insert(&collection, &item, ());
+		Self::deposit_event(Event::ItemMadeSoulbound { collection, item });
+		Ok(())
+	}
+
+	/// Disallow further unprivileged transfers for a whole collection.
+	///
+	/// Returns the weight consumed, `T::WeightInfo::freeze_collection()`.
+	pub fn do_freeze_collection(
+		collection: T::CollectionId,
+		maybe_check_freezer: Option<T::AccountId>,
+	) -> Result<Weight, DispatchError> {
+		Collection::<T, I>::try_mutate(collection, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
+			if let Some(check_freezer) = maybe_check_freezer {
+				ensure!(check_freezer == details.freezer, Error::<T, I>::NoPermission);
+			}
+
+			details.is_frozen = true;
+
+			Self::deposit_event(Event::<T, I>::CollectionFrozen { collection });
+			Ok(T::WeightInfo::freeze_collection())
+		})
+	}
+
+	/// Re-allow unprivileged transfers for a whole collection.
+	///
+	/// Returns the weight consumed, `T::WeightInfo::thaw_collection()`.
+	pub fn do_thaw_collection(
+		collection: T::CollectionId,
+		maybe_check_admin: Option<T::AccountId>,
+	) -> Result<Weight, DispatchError> {
+		Collection::<T, I>::try_mutate(collection, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
+			if let Some(check_admin) = maybe_check_admin {
+				ensure!(check_admin == details.admin, Error::<T, I>::NoPermission);
+			}
+
+			details.is_frozen = false;
+
+			Self::deposit_event(Event::<T, I>::CollectionThawed { collection });
+			Ok(T::WeightInfo::thaw_collection())
+		})
+	}
+
+	/// Ensure that `delegate` holds an approval to transfer the item with `details`, which hasn't
+	/// expired yet.
+	pub(crate) fn check_approval(
+		details: &ItemDetailsFor<T, I>,
+		delegate: &T::AccountId,
+	) -> DispatchResult {
+		let deadline = details.approvals.get(delegate).ok_or(Error::<T, I>::NoPermission)?;
+		if let Some(deadline) = deadline {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= *deadline, Error::<T, I>::ApprovalExpired);
+		}
+		Ok(())
+	}
+
+	/// Returns `true` if `collection` muted the item-level events of `category`.
+	pub(crate) fn is_event_muted(collection: &T::CollectionId, category: EventCategory) -> bool {
+		CollectionConfigOf::<T, I>::get(collection).muted_events.is_muted(category)
+	}
+
+	/// Cancel the transfer approvals of up to `max_items` items of `collection`, visiting them in
+	/// storage order after `start_after`, or from the start if it's `None`.
+	pub fn do_clear_all_approvals(
+		collection: T::CollectionId,
+		max_items: u32,
+		start_after: Option<T::ItemId>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, &check_owner),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		let mut items = match start_after {
+			Some(item) => Item::<T, I>::iter_prefix_from(
+				&collection,
+				Item::<T, I>::hashed_key_for(&collection, &item),
+			),
+			None => Item::<T, I>::iter_prefix(&collection),
+		};
+		// collected before writing, as the iteration is undefined while the map is changed.
+		let batch: Vec<_> = items.by_ref().take(max_items as usize).collect();
+		let resume_from = match items.next() {
+			Some(_) => batch.last().map(|(item, _)| *item).or(start_after),
+			None => None,
+		};
+
+		let mut cleared = 0u32;
+		for (item, mut details) in batch {
+			if !details.approvals.is_empty() {
+				details.approvals = Default::default();
+				Item::<T, I>::insert(&collection, &item, &details);
+				cleared.saturating_inc();
+			}
+		}
+
+		Self::deposit_event(Event::AllApprovalsCleared { collection, cleared, resume_from });
+		Ok(())
+	}
+
+	/// The deposit currently required to hold an item of a collection with `collection_details`.
+	///
+	/// This only covers the item itself. Each attribute of the item holds a deposit of its own,
+	/// of `AttributeDepositBase + DepositPerByte * (key.len + value.len)`, which is reserved and
+	/// unreserved as the attribute is set and cleared. The storage an item takes up is thus
+	/// covered by `ItemDeposit` plus the deposits of its attributes, without `refresh_item_deposit`
+	/// having to recompute anything when attributes change.
+	pub fn item_deposit(collection_details: &CollectionDetailsFor<T, I>) -> DepositBalanceOf<T, I> {
+		match collection_details.free_holding {
+			true => Zero::zero(),
+			false => T::ItemDeposit::get(),
+		}
+	}
+
+	pub fn do_refresh_item_deposit(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+		}
+		let mut details =
+			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+
+		let old = details.deposit;
+		let new = Self::item_deposit(&collection_details);
+		if new > old {
+			T::Currency::reserve(&collection_details.owner, new - old)?;
+		} else if old > new {
+			T::Currency::unreserve(&collection_details.owner, old - new);
+		}
+		collection_details.total_deposit.saturating_accrue(new);
+		collection_details.total_deposit.saturating_reduce(old);
+		details.deposit = new;
+
+		Item::<T, I>::insert(&collection, &item, &details);
+		Collection::<T, I>::insert(&collection, &collection_details);
+		Self::deposit_event(Event::ItemDepositRefreshed { collection, item, old, new });
+		Ok(())
+	}
+
+	pub fn do_set_role_many(
+		owner: T::AccountId,
+		assignments: Vec<(T::CollectionId, CollectionRole, T::AccountId)>,
+	) -> DispatchResult {
+		for (collection, ..) in assignments.iter() {
+			ensure!(Collection::<T, I>::contains_key(collection), Error::<T, I>::UnknownCollection);
+			ensure!(Self::is_collection_owner(*collection, &owner), Error::<T, I>::NoPermission);
+		}
+
+		for (collection, role, who) in assignments.iter() {
+			Collection::<T, I>::mutate(collection, |maybe_details| {
+				if let Some(details) = maybe_details {
+					match role {
+						CollectionRole::Issuer => details.issuer = who.clone(),
+						CollectionRole::Admin => details.admin = who.clone(),
+						CollectionRole::Freezer => details.freezer = who.clone(),
+					}
+				}
+			});
+		}
+
+		Self::deposit_event(Event::RolesSet { owner, assignments });
 		Ok(())
 	}
 
@@ -91,14 +555,76 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				item_metadatas: 0,
 				attributes: 0,
 				is_frozen: false,
+				has_recovery_account: false,
 			},
 		);
 
+		CollectionCreationDepositOf::<T, I>::insert(&collection, deposit);
 		CollectionAccount::<T, I>::insert(&owner, &collection, ());
+		OwnedCollectionsCountOf::<T, I>::mutate(&owner, |count| count.saturating_inc());
 		Self::deposit_event(event);
 		Ok(())
 	}
 
+	/// The deposit for `owner` to create a new collection, following `CollectionDepositTiers`.
+	pub fn collection_deposit(owner: &T::AccountId) -> DepositBalanceOf<T, I> {
+		T::CollectionDepositTiers::deposit(
+			T::CollectionDeposit::get(),
+			OwnedCollectionsCountOf::<T, I>::get(owner),
+		)
+	}
+
+	/// Record that `collection` is no longer owned by `from`, but by `to`.
+	pub(crate) fn move_collection_account(
+		collection: T::CollectionId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) {
+		CollectionAccount::<T, I>::remove(from, &collection);
+		Self::dec_owned_collections(from);
+		CollectionAccount::<T, I>::insert(to, &collection, ());
+		OwnedCollectionsCountOf::<T, I>::mutate(to, |count| count.saturating_inc());
+	}
+
+	fn dec_owned_collections(owner: &T::AccountId) {
+		OwnedCollectionsCountOf::<T, I>::mutate_exists(owner, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+		});
+	}
+
+	/// Whether `who` takes a slot among the distinct owners of a collection when holding its
+	/// items. The escrow and wrap accounts only hold items in custody.
+	pub(crate) fn counts_as_owner(who: &T::AccountId) -> bool {
+		*who != Self::escrow_account() && !Self::is_wrap_account(who)
+	}
+
+	/// Record `who` as holding `item`, counting it as a new owner of `collection` if it held none.
+	pub(crate) fn insert_account_item(
+		who: &T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) {
+		if Self::counts_as_owner(who) && !Self::owns_any_in_collection(who, collection) {
+			DistinctOwnersCountOf::<T, I>::mutate(&collection, |count| count.saturating_inc());
+		}
+		Account::<T, I>::insert((who, &collection, &item), ());
+	}
+
+	/// Record `who` as no longer holding `item`, and no longer as an owner of `collection` if that
+	/// was its last item.
+	pub(crate) fn remove_account_item(
+		who: &T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) {
+		Account::<T, I>::remove((who, &collection, &item));
+		if Self::counts_as_owner(who) && !Self::owns_any_in_collection(who, collection) {
+			DistinctOwnersCountOf::<T, I>::mutate_exists(&collection, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+			});
+		}
+	}
+
 	pub fn do_destroy_collection(
 		collection: T::CollectionId,
 		witness: DestroyWitness,
@@ -108,7 +634,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let collection_details =
 				maybe_details.take().ok_or(Error::<T, I>::UnknownCollection)?;
 			if let Some(check_owner) = maybe_check_owner {
-				ensure!(collection_details.owner == check_owner, Error::<T, I>::NoPermission);
+				ensure!(
+					Self::is_collection_owner(collection, &check_owner),
+					Error::<T, I>::NoPermission
+				);
 			}
 			ensure!(collection_details.items == witness.items, Error::<T, I>::BadWitness);
 			ensure!(
@@ -116,6 +645,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Error::<T, I>::BadWitness
 			);
 			ensure!(collection_details.attributes == witness.attributes, Error::<T, I>::BadWitness);
+			ensure!(
+				WrappedItemsOf::<T, I>::iter_prefix(&collection).next().is_none(),
+				Error::<T, I>::Wrapped
+			);
+			ensure!(
+				WrappedCountOf::<T, I>::iter_prefix(&collection).next().is_none(),
+				Error::<T, I>::HasWrappedItems
+			);
 
 			for (item, details) in Item::<T, I>::drain_prefix(&collection) {
 				Account::<T, I>::remove((&details.owner, &collection, &item));
@@ -124,12 +661,51 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ItemMetadataOf::<T, I>::remove_prefix(&collection, None);
 			#[allow(deprecated)]
 			ItemPriceOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			ItemNameOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			FirstTransferApprovedOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			SoulboundItemsOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			UnlockApprovalsOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			TransferCountOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			LastMetadataUpdateOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			LastMintByAccountOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			ItemMintedAt::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			LastTransferOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			ItemByNameOf::<T, I>::remove_prefix(&collection, None);
 			CollectionMetadataOf::<T, I>::remove(&collection);
+			UnrevealedMetadataOf::<T, I>::remove(&collection);
+			RevealCommitmentOf::<T, I>::remove(&collection);
 			#[allow(deprecated)]
 			Attribute::<T, I>::remove_prefix((&collection,), None);
 			CollectionAccount::<T, I>::remove(&collection_details.owner, &collection);
+			Self::dec_owned_collections(&collection_details.owner);
 			T::Currency::unreserve(&collection_details.owner, collection_details.total_deposit);
+			if let Some(bond) = CollectionBondOf::<T, I>::take(&collection) {
+				T::Currency::unreserve(&collection_details.owner, bond.amount);
+			}
 			CollectionMaxSupply::<T, I>::remove(&collection);
+			CollectionCreationDepositOf::<T, I>::remove(&collection);
+			CollectionConfigOf::<T, I>::remove(&collection);
+			MarketplacePolicyOf::<T, I>::remove(&collection);
+			BurnRequirementOf::<T, I>::remove(&collection);
+			NextItemIdOf::<T, I>::remove(&collection);
+			Self::remove_recurring_lock(collection);
+			MintsThisBlockOf::<T, I>::remove(&collection);
+			DistinctOwnersCountOf::<T, I>::remove(&collection);
+			for (_, escrow) in EscrowsOf::<T, I>::drain_prefix(&collection) {
+				if escrow.funded {
+					T::Currency::unreserve(&escrow.buyer, escrow.price);
+				}
+			}
 
 			Self::deposit_event(Event::Destroyed { collection });
 
@@ -141,14 +717,34 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Mint `item` with an explicit id.
+	///
+	/// Collections with `SequentialIds` only allocate their ids through `do_mint_next`, so that
+	/// the id it allocates next is never taken.
 	pub fn do_mint(
 		collection: T::CollectionId,
 		item: T::ItemId,
 		owner: T::AccountId,
+		maybe_minter: Option<T::AccountId>,
+		with_details: impl FnOnce(&CollectionDetailsFor<T, I>) -> DispatchResult,
+	) -> DispatchResult {
+		ensure!(
+			!Self::is_collection_setting_enabled(&collection, CollectionSetting::SequentialIds),
+			Error::<T, I>::SequentialIdsEnabled
+		);
+		Self::mint_item(collection, item, owner, maybe_minter, with_details)
+	}
+
+	fn mint_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		owner: T::AccountId,
+		maybe_minter: Option<T::AccountId>,
 		with_details: impl FnOnce(&CollectionDetailsFor<T, I>) -> DispatchResult,
 	) -> DispatchResult {
 		ensure!(!Item::<T, I>::contains_key(collection, item), Error::<T, I>::AlreadyExists);
 
+		let mut receipt_deposit = None;
 		Collection::<T, I>::try_mutate(
 			&collection,
 			|maybe_collection_details| -> DispatchResult {
@@ -161,32 +757,219 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					ensure!(collection_details.items < max_supply, Error::<T, I>::MaxSupplyReached);
 				}
 
+				let config = CollectionConfigOf::<T, I>::get(&collection);
+				Self::ensure_item_id_allowed(&config, collection_details, &item, &maybe_minter)?;
+				// minting is screened as a transfer from whoever brings the item into existence.
+				let source = maybe_minter.as_ref().unwrap_or(&collection_details.owner);
+				T::TransferPolicy::can_transfer(&collection, &item, source, &owner)?;
+				Self::ensure_owner_count_allowed(&collection, &config, None, &owner)?;
+
+				if let Some(max_mints) = config.max_mints_per_block {
+					let mints = MintsThisBlockOf::<T, I>::get(&collection);
+					ensure!(mints < max_mints, Error::<T, I>::MintRateLimited);
+					MintsThisBlockOf::<T, I>::insert(&collection, mints.saturating_add(1));
+				}
+
+				if let Some(minter) = &maybe_minter {
+					Self::ensure_mint_cooldown_passed(&collection, &config, minter)?;
+				}
+
+				if let (Some(minter), Some(stake)) = (&maybe_minter, config.mint_min_stake) {
+					Self::reserve_mint_stake(collection, item, minter, stake)?;
+				}
+
 				let items =
 					collection_details.items.checked_add(1).ok_or(ArithmeticError::Overflow)?;
 				collection_details.items = items;
 
-				let deposit = match collection_details.free_holding {
-					true => Zero::zero(),
-					false => T::ItemDeposit::get(),
-				};
+				let deposit = Self::item_deposit(collection_details);
 				T::Currency::reserve(&collection_details.owner, deposit)?;
 				collection_details.total_deposit += deposit;
+				if config.settings.is_enabled(CollectionSetting::MintReceipts) {
+					receipt_deposit = Some(deposit);
+				}
+
+				if config.settings.is_enabled(CollectionSetting::StampMintProvenance) {
+					let now = frame_system::Pallet::<T>::block_number();
+					let minted_by =
+						maybe_minter.clone().unwrap_or_else(|| collection_details.owner.clone());
+					for (key, value) in [
+						(MINTED_AT_ATTRIBUTE, now.encode()),
+						(MINTED_BY_ATTRIBUTE, minted_by.encode()),
+					] {
+						Self::stamp_attribute(
+							collection,
+							collection_details,
+							&config,
+							item,
+							key,
+							value,
+						)?;
+					}
+				}
 
 				let owner = owner.clone();
-				Account::<T, I>::insert((&owner, &collection, &item), ());
-				let details = ItemDetails { owner, approved: None, is_frozen: false, deposit };
+				Self::insert_account_item(&owner, collection, item);
+				let details =
+					ItemDetails { owner, approvals: Default::default(), is_frozen: false, deposit };
 				Item::<T, I>::insert(&collection, &item, details);
+				ItemMintedAt::<T, I>::insert(
+					&collection,
+					&item,
+					frame_system::Pallet::<T>::block_number(),
+				);
 				Ok(())
 			},
 		)?;
 
-		Self::deposit_event(Event::Issued { collection, item, owner });
+		Self::deposit_event(Event::Issued { collection, item, owner: owner.clone() });
+		if let Some(deposit_reserved) = receipt_deposit {
+			Self::deposit_event(Event::MintReceipt {
+				collection,
+				item,
+				minter: maybe_minter,
+				price_paid: Zero::zero(),
+				deposit_reserved,
+				beneficiary: owner,
+			});
+		}
+		Ok(())
+	}
+
+	/// Set the attribute `key` of a newly minted `item` to `value`, reserving the deposit from the
+	/// owner of the collection as `set_attribute` does.
+	fn stamp_attribute(
+		collection: T::CollectionId,
+		collection_details: &mut CollectionDetailsFor<T, I>,
+		config: &CollectionConfigFor<T, I>,
+		item: T::ItemId,
+		key: &[u8],
+		value: Vec<u8>,
+	) -> DispatchResult {
+		let key = BoundedVec::<u8, T::KeyLimit>::try_from(key.to_vec())
+			.map_err(|_| Error::<T, I>::ProvenanceTooLong)?;
+		let value = BoundedVec::<u8, T::ValueLimit>::try_from(value)
+			.map_err(|_| Error::<T, I>::ProvenanceTooLong)?;
+
+		let attribute = Attribute::<T, I>::get((collection, Some(item), &key));
+		if attribute.is_none() {
+			Self::ensure_attribute_allowed(&collection, collection_details)?;
+			collection_details.attributes.saturating_inc();
+		}
+		let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
+		collection_details.total_deposit.saturating_reduce(old_deposit);
+		let mut deposit = Zero::zero();
+		if !collection_details.free_holding &&
+			!config.settings.is_enabled(CollectionSetting::FreeAttributes)
+		{
+			deposit = T::DepositPerByte::get()
+				.saturating_mul(((key.len() + value.len()) as u32).into())
+				.saturating_add(T::AttributeDepositBase::get());
+		}
+		collection_details.total_deposit.saturating_accrue(deposit);
+		if deposit > old_deposit {
+			T::Currency::reserve(&collection_details.owner, deposit - old_deposit)?;
+		} else if deposit < old_deposit {
+			T::Currency::unreserve(&collection_details.owner, old_deposit - deposit);
+		}
+
+		Attribute::<T, I>::insert((&collection, Some(item), &key), (&value, deposit));
+		if !Self::is_event_muted(&collection, EventCategory::Attributes) {
+			Self::deposit_event(Event::AttributeSet {
+				collection,
+				maybe_item: Some(item),
+				key,
+				value,
+			});
+		}
+		Ok(())
+	}
+
+	/// Mint the next item of a collection with `SequentialIds`, returning its id.
+	///
+	/// The counter only ever moves forward, so ids of burned items are not handed out again.
+	pub fn do_mint_next(
+		collection: T::CollectionId,
+		owner: T::AccountId,
+		maybe_minter: Option<T::AccountId>,
+		with_details: impl FnOnce(&CollectionDetailsFor<T, I>) -> DispatchResult,
+	) -> Result<T::ItemId, DispatchError> {
+		ensure!(
+			Self::is_collection_setting_enabled(&collection, CollectionSetting::SequentialIds),
+			Error::<T, I>::SequentialIdsDisabled
+		);
+
+		let item = Self::sequential_item_id(collection)?;
+		let next = item.increment().ok_or(Error::<T, I>::NoAvailableItemId)?;
+
+		Self::mint_item(collection, item, owner, maybe_minter, with_details)?;
+		NextItemIdOf::<T, I>::insert(&collection, next);
+		Ok(item)
+	}
+
+	/// The id `do_mint_next` allocates next within `collection`, skipping over the id the
+	/// counter points at if it doesn't have the `id_parity` of the collection.
+	pub(crate) fn sequential_item_id(
+		collection: T::CollectionId,
+	) -> Result<T::ItemId, DispatchError> {
+		let item = NextItemIdOf::<T, I>::get(&collection).unwrap_or_else(T::ItemId::initial_value);
+		if CollectionConfigOf::<T, I>::get(&collection).id_parity.allows(&item) {
+			Ok(item)
+		} else {
+			item.increment().ok_or(Error::<T, I>::NoAvailableItemId.into())
+		}
+	}
+
+	/// Reserve `stake` from `minter` of `item` until `MintStakeCooldown` blocks from now.
+	fn reserve_mint_stake(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		minter: &T::AccountId,
+		stake: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		T::Currency::reserve(minter, stake)?;
+		// stakes released in the current block would never be released.
+		let release_at = frame_system::Pallet::<T>::block_number()
+			.saturating_add(T::MintStakeCooldown::get().max(One::one()));
+		MintStakeOf::<T, I>::mutate(release_at, minter, |staked| staked.saturating_accrue(stake));
+
+		Self::deposit_event(Event::MintStaked {
+			collection,
+			item,
+			who: minter.clone(),
+			amount: stake,
+			release_at,
+		});
 		Ok(())
 	}
 
+	/// Release all mint stakes due at block `now`.
+	pub(crate) fn release_mint_stakes(now: T::BlockNumber) -> Weight {
+		let mut count = 0;
+		for (who, amount) in MintStakeOf::<T, I>::drain_prefix(now) {
+			T::Currency::unreserve(&who, amount);
+			Self::deposit_event(Event::MintStakeReleased { who, amount });
+			count += 1;
+		}
+		T::WeightInfo::release_mint_stakes(count)
+	}
+
 	pub fn do_burn(
 		collection: T::CollectionId,
 		item: T::ItemId,
+		maybe_burner: Option<T::AccountId>,
+		with_details: impl FnOnce(&CollectionDetailsFor<T, I>, &ItemDetailsFor<T, I>) -> DispatchResult,
+	) -> DispatchResult {
+		Self::burn_item(collection, item, maybe_burner, false, with_details)
+	}
+
+	/// Burn `item`, where `via_sink` tells whether that is because it was transferred to the
+	/// sink of the collection.
+	fn burn_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_burner: Option<T::AccountId>,
+		via_sink: bool,
 		with_details: impl FnOnce(&CollectionDetailsFor<T, I>, &ItemDetailsFor<T, I>) -> DispatchResult,
 	) -> DispatchResult {
 		let owner = Collection::<T, I>::try_mutate(
@@ -197,6 +980,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				let details = Item::<T, I>::get(&collection, &item)
 					.ok_or(Error::<T, I>::UnknownCollection)?;
 				with_details(collection_details, &details)?;
+				ensure!(
+					!EscrowsOf::<T, I>::contains_key(&collection, &item),
+					Error::<T, I>::InEscrow
+				);
+				Self::ensure_unwrapped(&collection, &item)?;
+				Self::ensure_burn_condition_met(&collection, &item)?;
 
 				// Return the deposit.
 				T::Currency::unreserve(&collection_details.owner, details.deposit);
@@ -207,13 +996,149 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		)?;
 
 		Item::<T, I>::remove(&collection, &item);
-		Account::<T, I>::remove((&owner, &collection, &item));
+		Self::remove_account_item(&owner, collection, item);
 		ItemPriceOf::<T, I>::remove(&collection, &item);
+		FirstTransferApprovedOf::<T, I>::remove(&collection, &item);
+		SoulboundItemsOf::<T, I>::remove(&collection, &item);
+		UnlockApprovalsOf::<T, I>::remove(&collection, &item);
+		TransferCountOf::<T, I>::remove(&collection, &item);
+		LastMetadataUpdateOf::<T, I>::remove(&collection, &item);
+		ItemMintedAt::<T, I>::remove(&collection, &item);
+		LastTransferOf::<T, I>::remove(&collection, &item);
+		Self::remove_item_name(collection, item);
+
+		Self::deposit_event(Event::Burned {
+			collection,
+			item,
+			owner,
+			burner: maybe_burner,
+			via_sink,
+		});
+		Ok(())
+	}
+
+	pub fn do_set_item_name(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		name: BoundedVec<u8, T::ItemNameLimit>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		Self::ensure_content_writable(&collection, &maybe_check_owner)?;
+
+		let unique =
+			Self::is_collection_setting_enabled(&collection, CollectionSetting::UniqueItemNames);
+		if unique {
+			let taken = ItemByNameOf::<T, I>::get(&collection, &name).map_or(false, |i| i != item);
+			ensure!(!taken, Error::<T, I>::NameTaken);
+		}
+
+		Self::remove_item_name(collection, item);
+		if unique {
+			ItemByNameOf::<T, I>::insert(&collection, &name, item);
+		}
+		ItemNameOf::<T, I>::insert(&collection, &item, &name);
 
-		Self::deposit_event(Event::Burned { collection, item, owner });
+		Self::deposit_event(Event::ItemNameSet { collection, item, name });
 		Ok(())
 	}
 
+	pub fn do_set_unrevealed_metadata(
+		collection: T::CollectionId,
+		data: BoundedVec<u8, T::StringLimit>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+		}
+		Self::ensure_content_writable(&collection, &maybe_check_owner)?;
+		ensure!(
+			!Self::is_collection_setting_enabled(&collection, CollectionSetting::Revealed),
+			Error::<T, I>::AlreadyRevealed
+		);
+		Self::ensure_metadata_allowed(&collection, &data)?;
+
+		UnrevealedMetadataOf::<T, I>::insert(&collection, &data);
+		Self::deposit_event(Event::UnrevealedMetadataSet { collection, data });
+		Ok(())
+	}
+
+	pub fn do_reveal_collection(
+		collection: T::CollectionId,
+		maybe_metadata_root: Option<BoundedVec<u8, T::StringLimit>>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+		}
+		if let Some(commitment) = RevealCommitmentOf::<T, I>::get(&collection) {
+			let root = maybe_metadata_root.ok_or(Error::<T, I>::RevealMismatch)?;
+			ensure!(T::Hashing::hash(&root) == commitment, Error::<T, I>::RevealMismatch);
+		}
+
+		CollectionConfigOf::<T, I>::try_mutate(&collection, |config| -> DispatchResult {
+			ensure!(
+				!config.settings.is_enabled(CollectionSetting::Revealed),
+				Error::<T, I>::AlreadyRevealed
+			);
+			config.settings.0.insert(CollectionSetting::Revealed);
+			Ok(())
+		})?;
+		UnrevealedMetadataOf::<T, I>::remove(&collection);
+		Self::deposit_event(Event::CollectionRevealed { collection });
+		Ok(())
+	}
+
+	pub fn do_commit_reveal_hash(
+		collection: T::CollectionId,
+		hash: T::Hash,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+			ensure!(
+				!RevealCommitmentOf::<T, I>::contains_key(&collection),
+				Error::<T, I>::AlreadyCommitted
+			);
+		}
+		ensure!(
+			!Self::is_collection_setting_enabled(&collection, CollectionSetting::Revealed),
+			Error::<T, I>::AlreadyRevealed
+		);
+
+		RevealCommitmentOf::<T, I>::insert(&collection, hash);
+		Self::deposit_event(Event::RevealCommitted { collection, hash });
+		Ok(())
+	}
+
+	/// Remove the name of `item`, along with the reverse lookup pointing at it.
+	fn remove_item_name(collection: T::CollectionId, item: T::ItemId) {
+		if let Some(name) = ItemNameOf::<T, I>::take(&collection, &item) {
+			if ItemByNameOf::<T, I>::get(&collection, &name) == Some(item) {
+				ItemByNameOf::<T, I>::remove(&collection, &name);
+			}
+		}
+	}
+
 	pub fn do_set_price(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -225,16 +1150,39 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
 
 		if let Some(ref price) = price {
+			let fee = CollectionConfigOf::<T, I>::get(&collection).listing_fee;
+			match fee {
+				Some(fee) if !ItemPriceOf::<T, I>::contains_key(&collection, &item) => {
+					let imbalance = T::Currency::withdraw(
+						&sender,
+						fee,
+						WithdrawReasons::FEE,
+						ExistenceRequirement::KeepAlive,
+					)?;
+					T::FeeCollector::on_unbalanced(imbalance);
+					Self::deposit_event(Event::ListingFeePaid {
+						collection,
+						item,
+						lister: sender,
+						fee,
+					});
+				},
+				_ => {},
+			}
 			ItemPriceOf::<T, I>::insert(&collection, &item, (price, whitelisted_buyer.clone()));
-			Self::deposit_event(Event::ItemPriceSet {
-				collection,
-				item,
-				price: *price,
-				whitelisted_buyer,
-			});
+			if !Self::is_event_muted(&collection, EventCategory::Trading) {
+				Self::deposit_event(Event::ItemPriceSet {
+					collection,
+					item,
+					price: *price,
+					whitelisted_buyer,
+				});
+			}
 		} else {
 			ItemPriceOf::<T, I>::remove(&collection, &item);
-			Self::deposit_event(Event::ItemPriceRemoved { collection, item });
+			if !Self::is_event_muted(&collection, EventCategory::Trading) {
+				Self::deposit_event(Event::ItemPriceRemoved { collection, item });
+			}
 		}
 
 		Ok(())
@@ -268,15 +1216,71 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let old_owner = details.owner.clone();
 
 		Self::do_transfer(collection, item, buyer.clone(), |_, _| Ok(()))?;
+		Self::freeze_after_transfer(collection, item);
 
-		Self::deposit_event(Event::ItemBought {
-			collection,
-			item,
-			price: price_info.0,
-			seller: old_owner,
-			buyer,
-		});
+		if !Self::is_event_muted(&collection, EventCategory::Trading) {
+			Self::deposit_event(Event::ItemBought {
+				collection,
+				item,
+				price: price_info.0,
+				seller: old_owner,
+				buyer,
+			});
+		}
+
+		Ok(())
+	}
 
+	/// The sum of the deposits recorded for the metadata, items and attributes of `collection`.
+	pub(crate) fn recorded_deposits(collection: T::CollectionId) -> DepositBalanceOf<T, I> {
+		let mut recorded = CollectionMetadataOf::<T, I>::get(&collection)
+			.map_or_else(Zero::zero, |metadata| metadata.deposit);
+		for item in Item::<T, I>::iter_prefix_values(&collection) {
+			recorded.saturating_accrue(item.deposit);
+		}
+		for metadata in ItemMetadataOf::<T, I>::iter_prefix_values(&collection) {
+			recorded.saturating_accrue(metadata.deposit);
+		}
+		for (_, deposit) in Attribute::<T, I>::iter_prefix_values((&collection,)) {
+			recorded.saturating_accrue(deposit);
+		}
+		recorded
+	}
+
+	/// Check that the deposits recorded for `collection` and its creation add up to its
+	/// `total_deposit`, and that its owner actually has at least as much reserved.
+	pub fn check_deposits(collection: T::CollectionId) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+
+		let recorded = Self::recorded_deposits(collection)
+			.saturating_add(CollectionCreationDepositOf::<T, I>::get(&collection));
+		ensure!(recorded == details.total_deposit, Error::<T, I>::DepositMismatch);
+		ensure!(
+			details.total_deposit <= T::Currency::reserved_balance(&details.owner),
+			Error::<T, I>::DepositMismatch
+		);
+		Ok(())
+	}
+
+	/// Ensure the deposits of every collection add up, see [`Self::check_deposits`], and that
+	/// every owner has the deposits of all of their collections reserved.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub(crate) fn do_try_state() -> Result<(), &'static str> {
+		let mut owner_deposits = sp_std::collections::btree_map::BTreeMap::new();
+		for (collection, details) in Collection::<T, I>::iter() {
+			Self::check_deposits(collection).map_err(|_| "collection deposits don't add up")?;
+			owner_deposits
+				.entry(details.owner)
+				.or_insert_with(Zero::zero)
+				.saturating_accrue(details.total_deposit);
+		}
+		for (owner, deposit) in owner_deposits {
+			ensure!(
+				deposit <= T::Currency::reserved_balance(&owner),
+				"collection owner has less reserved than its collections' deposits"
+			);
+		}
 		Ok(())
 	}
 }
@@ -52,7 +52,7 @@ impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Palle
 	) -> Option<Vec<u8>> {
 		if key.is_empty() {
 			// We make the empty key map to the item metadata value.
-			ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data.into())
+			Self::item_metadata(*collection, *item)
 		} else {
 			let key = BoundedSlice::<_, _>::try_from(key).ok()?;
 			Attribute::<T, I>::get((collection, Some(item), key)).map(|a| a.0.into())
@@ -96,7 +96,7 @@ impl<T: Config<I>, I: 'static> Create<<T as SystemConfig>::AccountId> for Pallet
 			*collection,
 			who.clone(),
 			admin.clone(),
-			T::CollectionDeposit::get(),
+			Self::collection_deposit(who),
 			false,
 			Event::Created { collection: *collection, creator: who.clone(), owner: admin.clone() },
 		)
@@ -125,7 +125,7 @@ impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId> for Pallet
 		item: &Self::ItemId,
 		who: &T::AccountId,
 	) -> DispatchResult {
-		Self::do_mint(*collection, *item, who.clone(), |_| Ok(()))
+		Self::do_mint(*collection, *item, who.clone(), None, |_| Ok(()))
 	}
 
 	fn burn(
@@ -133,7 +133,7 @@ impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId> for Pallet
 		item: &Self::ItemId,
 		maybe_check_owner: Option<&T::AccountId>,
 	) -> DispatchResult {
-		Self::do_burn(*collection, *item, |_, d| {
+		Self::do_burn(*collection, *item, maybe_check_owner.cloned(), |_, d| {
 			if let Some(check_owner) = maybe_check_owner {
 				if &d.owner != check_owner {
 					return Err(Error::<T, I>::NoPermission.into())
@@ -150,7 +150,9 @@ impl<T: Config<I>, I: 'static> Transfer<T::AccountId> for Pallet<T, I> {
 		item: &Self::ItemId,
 		destination: &T::AccountId,
 	) -> DispatchResult {
-		Self::do_transfer(*collection, *item, destination.clone(), |_, _| Ok(()))
+		Self::do_transfer(*collection, *item, destination.clone(), |_, _| Ok(()))?;
+		Self::freeze_after_transfer(*collection, *item);
+		Ok(())
 	}
 }
 
@@ -35,9 +35,16 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
+mod bond;
+mod escrow;
 mod functions;
 mod impl_nonfungibles;
+mod recurring_lock;
+mod settings;
+#[cfg(feature = "std")]
+mod snapshot;
 mod types;
+mod wrap;
 
 pub mod migration;
 pub mod weights;
@@ -45,18 +52,21 @@ pub mod weights;
 use codec::{Decode, Encode};
 use frame_support::{
 	traits::{
-		tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, ReservableCurrency,
+		tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, OnUnbalanced,
+		ReservableCurrency,
 	},
-	transactional,
+	transactional, PalletId,
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
-	traits::{Saturating, StaticLookup, Zero},
+	traits::{AtLeast32BitUnsigned, Saturating, StaticLookup, Zero},
 	ArithmeticError, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
 pub use pallet::*;
+#[cfg(feature = "std")]
+pub use snapshot::CollectionSnapshot;
 pub use types::*;
 pub use weights::WeightInfo;
 
@@ -68,8 +78,12 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -87,6 +101,75 @@ pub mod pallet {
 		}
 	}
 
+	/// Converts collection ids to and from their canonical `u128` representation, which is how
+	/// they are exposed to other chains, such as NFT bridges.
+	pub trait CollectionIdConverter<CollectionId> {
+		/// The canonical representation of `collection`, if it has one.
+		fn to_canonical(collection: CollectionId) -> Option<u128>;
+		/// The collection id represented by `canonical`, if any.
+		fn from_canonical(canonical: u128) -> Option<CollectionId>;
+	}
+	impl<CollectionId: Into<u128> + TryFrom<u128>> CollectionIdConverter<CollectionId> for () {
+		fn to_canonical(collection: CollectionId) -> Option<u128> {
+			Some(collection.into())
+		}
+		fn from_canonical(canonical: u128) -> Option<CollectionId> {
+			canonical.try_into().ok()
+		}
+	}
+
+	/// Decides whether an item may be transferred, allowing e.g. compliance modules to veto
+	/// specific transfers.
+	pub trait TransferPolicy<AccountId, CollectionId, ItemId> {
+		/// Check whether `item` of `collection` may be transferred from `from` to `to`.
+		///
+		/// Mints are checked too, with `from` being the minter, or the owner of the collection if
+		/// the item is minted without one. An error aborts the transfer before any state is
+		/// changed.
+		fn can_transfer(
+			collection: &CollectionId,
+			item: &ItemId,
+			from: &AccountId,
+			to: &AccountId,
+		) -> DispatchResult;
+	}
+	impl<AccountId, CollectionId, ItemId> TransferPolicy<AccountId, CollectionId, ItemId> for () {
+		fn can_transfer(
+			_collection: &CollectionId,
+			_item: &ItemId,
+			_from: &AccountId,
+			_to: &AccountId,
+		) -> DispatchResult {
+			Ok(())
+		}
+	}
+
+	/// Decides the deposit for creating a collection, given the number of collections the creator
+	/// already owns. Allows collection creation to become more expensive for prolific owners.
+	pub trait CollectionDepositTiers<Balance> {
+		/// The deposit for a new collection of an account already owning `owned` collections,
+		/// where `base` is the `CollectionDeposit`.
+		fn deposit(base: Balance, owned: u32) -> Balance;
+	}
+	impl<Balance> CollectionDepositTiers<Balance> for () {
+		fn deposit(base: Balance, _owned: u32) -> Balance {
+			base
+		}
+	}
+
+	/// Charges the base deposit for the first `FreeTier` collections of an account, then one more
+	/// base deposit for every further collection: the `n`th collection beyond the free tier costs
+	/// `(n + 1) * base`. The deposit is refunded in full when the collection is destroyed.
+	pub struct EscalatingDeposit<FreeTier>(PhantomData<FreeTier>);
+	impl<Balance: AtLeast32BitUnsigned, FreeTier: Get<u32>> CollectionDepositTiers<Balance>
+		for EscalatingDeposit<FreeTier>
+	{
+		fn deposit(base: Balance, owned: u32) -> Balance {
+			let tier = owned.saturating_add(2).saturating_sub(FreeTier::get()).max(1);
+			base.saturating_mul(tier.into())
+		}
+	}
+
 	#[pallet::config]
 	/// The module configuration trait.
 	pub trait Config<I: 'static = ()>: frame_system::Config {
@@ -98,7 +181,12 @@ pub mod pallet {
 		type CollectionId: Member + Parameter + MaxEncodedLen + Copy;
 
 		/// The type used to identify a unique item within a collection.
-		type ItemId: Member + Parameter + MaxEncodedLen + Copy;
+		///
+		/// Collections with `SequentialIds` allocate these in sequence, see `mint_next`.
+		type ItemId: Member + Parameter + MaxEncodedLen + Copy + PartialOrd + Incrementable;
+
+		/// Converts collection ids to and from the canonical form used to expose them externally.
+		type CollectionIdConverter: CollectionIdConverter<Self::CollectionId>;
 
 		/// The currency mechanism, used for paying for reserves.
 		type Currency: ReservableCurrency<Self::AccountId>;
@@ -118,10 +206,17 @@ pub mod pallet {
 		/// Locker trait to enable Locking mechanism downstream.
 		type Locker: Locker<Self::CollectionId, Self::ItemId>;
 
+		/// Consulted before every transfer, which is aborted if the policy rejects it.
+		type TransferPolicy: TransferPolicy<Self::AccountId, Self::CollectionId, Self::ItemId>;
+
 		/// The basic amount of funds that must be reserved for collection.
 		#[pallet::constant]
 		type CollectionDeposit: Get<DepositBalanceOf<Self, I>>;
 
+		/// The schedule scaling the `CollectionDeposit` by the number of collections the creator
+		/// already owns.
+		type CollectionDepositTiers: CollectionDepositTiers<DepositBalanceOf<Self, I>>;
+
 		/// The basic amount of funds that must be reserved for an item.
 		#[pallet::constant]
 		type ItemDeposit: Get<DepositBalanceOf<Self, I>>;
@@ -143,6 +238,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type StringLimit: Get<u32>;
 
+		/// The maximum length of an item name.
+		#[pallet::constant]
+		type ItemNameLimit: Get<u32>;
+
 		/// The maximum length of an attribute key.
 		#[pallet::constant]
 		type KeyLimit: Get<u32>;
@@ -151,6 +250,57 @@ pub mod pallet {
 		#[pallet::constant]
 		type ValueLimit: Get<u32>;
 
+		/// The pallet's id, used for deriving the account which holds escrowed items.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The number of blocks after which an escrow that was not settled expires.
+		#[pallet::constant]
+		type EscrowTimeout: Get<Self::BlockNumber>;
+
+		/// The maximum number of escrows which may expire in the same block.
+		#[pallet::constant]
+		type MaxEscrowsPerBlock: Get<u32>;
+
+		/// The number of blocks a mint stake stays reserved for. Always at least one block.
+		#[pallet::constant]
+		type MintStakeCooldown: Get<Self::BlockNumber>;
+
+		/// The maximum number of collections which may have a recurring lock.
+		#[pallet::constant]
+		type MaxRecurringLocks: Get<u32>;
+
+		/// The maximum number of accounts an item may be approved for transfer by at once.
+		#[pallet::constant]
+		type MaxApprovalsPerItem: Get<u32>;
+
+		/// The maximum number of role assignments in a single `set_role_many` call.
+		#[pallet::constant]
+		type MaxRoleAssignments: Get<u32>;
+
+		/// The maximum number of items whose owners may be queried at once through `owners_of`.
+		#[pallet::constant]
+		type MaxItemsPerQuery: Get<u32>;
+
+		/// The maximum number of marketplace operators in the allow- or denylist of a collection.
+		#[pallet::constant]
+		type MaxMarketplaces: Get<u32>;
+
+		/// The maximum number of items in a single `transfer_many_to` call.
+		#[pallet::constant]
+		type MaxItemsPerTransfer: Get<u32>;
+
+		/// The number of blocks a collection bond has to go without being slashed before it may
+		/// be released.
+		#[pallet::constant]
+		type BondReleaseCooldown: Get<Self::BlockNumber>;
+
+		/// The handler of the funds slashed from collection bonds, e.g. the treasury.
+		type BondSlashed: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+
+		/// The handler of the listing fees charged by collections, e.g. the treasury.
+		type FeeCollector: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
 		type Helper: BenchmarkHelper<Self::CollectionId, Self::ItemId>;
@@ -211,7 +361,7 @@ pub mod pallet {
 		T::CollectionId,
 		Blake2_128Concat,
 		T::ItemId,
-		ItemDetails<T::AccountId, DepositBalanceOf<T, I>>,
+		ItemDetailsFor<T, I>,
 		OptionQuery,
 	>;
 
@@ -269,6 +419,267 @@ pub mod pallet {
 	pub(super) type CollectionMaxSupply<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, OptionQuery>;
 
+	#[pallet::storage]
+	/// The configuration of a collection.
+	pub(super) type CollectionConfigOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, CollectionConfigFor<T, I>, ValueQuery>;
+
+	#[pallet::storage]
+	/// The mint stakes to be released at a given block, by minter.
+	pub(super) type MintStakeOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		T::AccountId,
+		DepositBalanceOf<T, I>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The open escrow, if any, through which an item is being sold.
+	pub(super) type EscrowsOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		EscrowDetailsFor<T, I>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The escrows expiring at a given block.
+	pub(super) type EscrowExpiries<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<(T::CollectionId, T::ItemId), T::MaxEscrowsPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The recurring lock of a collection.
+	pub(super) type RecurringLocksOf<T: Config<I>, I: 'static = ()> = CountedStorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		RecurringLock<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The collections whose recurring lock is applied or lifted at a given block.
+	pub(super) type RecurringLockTransitions<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<T::CollectionId, T::MaxRecurringLocks>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The item a wrapped item is held inside of.
+	pub(super) type WrappedItemsOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		(T::CollectionId, T::ItemId),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The number of items wrapped inside an item.
+	pub(super) type WrappedCountOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		u32,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The role holders of a collection which approved the thaw of a frozen item.
+	pub(super) type UnlockApprovalsOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		UnlockApprovalsFor<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The items which are permanently frozen to their owner.
+	pub(super) type SoulboundItemsOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The items whose first transfer was approved, within collections which require it.
+	pub(super) type FirstTransferApprovedOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The number of distinct accounts holding at least one item of a collection.
+	pub(super) type DistinctOwnersCountOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// The number of collections an account owns.
+	pub(super) type OwnedCollectionsCountOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// The number of times an item has been transferred since it was minted.
+	pub(super) type TransferCountOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// The block in which the metadata of an item was last set.
+	pub(super) type LastMetadataUpdateOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The block in which an item was minted.
+	///
+	/// Only known for items minted since this map was introduced, as the mint block of older
+	/// items is not recorded anywhere else.
+	pub(super) type ItemMintedAt<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The block in which an item was last transferred.
+	pub(super) type LastTransferOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The block in which an account last minted an item of a collection.
+	pub(super) type LastMintByAccountOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The bond posted by the owner of a collection, which may be slashed for abusive content.
+	pub(super) type CollectionBondOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, CollectionBondFor<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	/// The marketplace operators which may be approved to transfer the items of a collection.
+	pub(super) type MarketplacePolicyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, MarketplacePolicyFor<T, I>, ValueQuery>;
+
+	#[pallet::storage]
+	/// The attribute the items of a collection have to carry for them to be burned.
+	pub(super) type BurnRequirementOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, BurnRequirementFor<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	/// The metadata shown for items without metadata of their own, until a collection is revealed.
+	pub(super) type UnrevealedMetadataOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		BoundedVec<u8, T::StringLimit>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The hash of the metadata root a collection committed to be revealed with.
+	pub(super) type RevealCommitmentOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, T::Hash, OptionQuery>;
+
+	#[pallet::storage]
+	/// The id `mint_next` will allocate next within a collection with `SequentialIds`.
+	pub(super) type NextItemIdOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, T::ItemId, OptionQuery>;
+
+	#[pallet::storage]
+	/// The on-chain name of an item.
+	pub(super) type ItemNameOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		BoundedVec<u8, T::ItemNameLimit>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The item carrying a name, within collections with `UniqueItemNames`.
+	pub(super) type ItemByNameOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		BoundedVec<u8, T::ItemNameLimit>,
+		T::ItemId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The number of items minted within the current block, for collections which limit it.
+	pub(super) type MintsThisBlockOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// The deposit reserved for the creation of a collection, as part of its `total_deposit`.
+	pub(super) type CollectionCreationDepositOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, DepositBalanceOf<T, I>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -280,6 +691,18 @@ pub mod pallet {
 		Destroyed { collection: T::CollectionId },
 		/// An `item` was issued.
 		Issued { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
+		/// The receipt of minting an `item` of a collection with `MintReceipts` switched on.
+		///
+		/// The `deposit_reserved` is reserved from the owner of the collection. As minting is
+		/// not priced by this pallet, `price_paid` is zero.
+		MintReceipt {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			minter: Option<T::AccountId>,
+			price_paid: ItemPrice<T, I>,
+			deposit_reserved: DepositBalanceOf<T, I>,
+			beneficiary: T::AccountId,
+		},
 		/// An `item` was transferred.
 		Transferred {
 			collection: T::CollectionId,
@@ -287,8 +710,15 @@ pub mod pallet {
 			from: T::AccountId,
 			to: T::AccountId,
 		},
-		/// An `item` was destroyed.
-		Burned { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
+		/// An `item` was destroyed by `burner`, which is `None` if it wasn't burned by an account.
+		/// `via_sink` is set if it was burned by being transferred to the sink of its collection.
+		Burned {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			owner: T::AccountId,
+			burner: Option<T::AccountId>,
+			via_sink: bool,
+		},
 		/// Some `item` was frozen.
 		Frozen { collection: T::CollectionId, item: T::ItemId },
 		/// Some `item` was thawed.
@@ -307,12 +737,13 @@ pub mod pallet {
 			freezer: T::AccountId,
 		},
 		/// An `item` of a `collection` has been approved by the `owner` for transfer by
-		/// a `delegate`.
+		/// a `delegate`, until the `deadline` if there is one.
 		ApprovedTransfer {
 			collection: T::CollectionId,
 			item: T::ItemId,
 			owner: T::AccountId,
 			delegate: T::AccountId,
+			deadline: Option<T::BlockNumber>,
 		},
 		/// An approval for a `delegate` account to transfer the `item` of an item
 		/// `collection` was cancelled by its `owner`.
@@ -338,6 +769,7 @@ pub mod pallet {
 			item: T::ItemId,
 			data: BoundedVec<u8, T::StringLimit>,
 			is_frozen: bool,
+			storage_proof: Option<BoundedVec<u8, T::StringLimit>>,
 		},
 		/// Metadata has been cleared for an item.
 		MetadataCleared { collection: T::CollectionId, item: T::ItemId },
@@ -377,46 +809,313 @@ pub mod pallet {
 			seller: T::AccountId,
 			buyer: T::AccountId,
 		},
-	}
-
-	#[pallet::error]
-	pub enum Error<T, I = ()> {
-		/// The signing account has no permission to do the operation.
-		NoPermission,
-		/// The given item ID is unknown.
-		UnknownCollection,
-		/// The item ID has already been used for an item.
-		AlreadyExists,
-		/// The owner turned out to be different to what was expected.
-		WrongOwner,
-		/// Invalid witness data given.
-		BadWitness,
-		/// The item ID is already taken.
-		InUse,
-		/// The item or collection is frozen.
-		Frozen,
-		/// The delegate turned out to be different to what was expected.
-		WrongDelegate,
-		/// There is no delegate approved.
-		NoDelegate,
-		/// No approval exists that would allow the transfer.
-		Unapproved,
-		/// The named owner has not signed ownership of the collection is acceptable.
-		Unaccepted,
-		/// The item is locked.
-		Locked,
-		/// All items have been minted.
-		MaxSupplyReached,
-		/// The max supply has already been set.
-		MaxSupplyAlreadySet,
-		/// The provided max supply is less to the amount of items a collection already has.
-		MaxSupplyTooSmall,
+		/// The configuration of a `collection` was changed.
+		CollectionConfigChanged { collection: T::CollectionId, config: CollectionConfigFor<T, I> },
+		/// The minter of an `item` staked `amount`, which is released at block `release_at`.
+		MintStaked {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			who: T::AccountId,
+			amount: DepositBalanceOf<T, I>,
+			release_at: T::BlockNumber,
+		},
+		/// The mint stakes of `who` worth `amount` were released.
+		MintStakeReleased { who: T::AccountId, amount: DepositBalanceOf<T, I> },
+		/// The `collection` is frozen for `duration` blocks out of every `period`.
+		RecurringLockSet {
+			collection: T::CollectionId,
+			duration: T::BlockNumber,
+			period: T::BlockNumber,
+		},
+		/// The recurring lock of the `collection` was cancelled.
+		RecurringLockCancelled { collection: T::CollectionId },
+		/// The metadata, attributes and names of the `collection` may no longer be changed.
+		CollectionContentFrozen { collection: T::CollectionId },
+		/// An `item` was wrapped inside the `parent_item` of the `parent_collection`.
+		ItemWrapped {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			parent_collection: T::CollectionId,
+			parent_item: T::ItemId,
+		},
+		/// An `item` was unwrapped and handed to the `owner` of its former parent.
+		ItemUnwrapped { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
+		/// An `item` was frozen after being transferred, as its collection requires.
+		ItemTransferLocked { collection: T::CollectionId, item: T::ItemId },
+		/// The placeholder metadata of the items of a `collection` was set.
+		UnrevealedMetadataSet { collection: T::CollectionId, data: BoundedVec<u8, T::StringLimit> },
+		/// The items of a `collection` were revealed.
+		CollectionRevealed { collection: T::CollectionId },
+		/// A `collection` committed to the `hash` of the metadata root it will be revealed with.
+		RevealCommitted { collection: T::CollectionId, hash: T::Hash },
+		/// The marketplace policy of a `collection` was set.
+		MarketplacePolicySet { collection: T::CollectionId, policy: MarketplacePolicyFor<T, I> },
+		/// The attribute the items of a `collection` must carry to be burned was set or removed.
+		BurnRequirementSet {
+			collection: T::CollectionId,
+			requirement: Option<BurnRequirementFor<T, I>>,
+		},
+		/// The transfer approvals of `cleared` items of a `collection` were cancelled at once.
+		/// If `resume_from` is set, items after it may still carry approvals.
+		AllApprovalsCleared {
+			collection: T::CollectionId,
+			cleared: u32,
+			resume_from: Option<T::ItemId>,
+		},
+		/// An `item` was permanently frozen to its owner.
+		ItemMadeSoulbound { collection: T::CollectionId, item: T::ItemId },
+		/// The last `Transferred` event was forced by the `ForceOrigin`.
+		ItemForceTransferred { collection: T::CollectionId, item: T::ItemId },
+		/// The last `Transferred` event was a reclaim by the recovery account of the `collection`.
+		ItemRecovered { collection: T::CollectionId, item: T::ItemId },
+		/// The `items` of a `collection` were transferred to `dest` at once.
+		ItemsTransferred {
+			collection: T::CollectionId,
+			items: BoundedVec<T::ItemId, T::MaxItemsPerTransfer>,
+			dest: T::AccountId,
+		},
+		/// The owner of a `collection` posted a bond of `amount` in total.
+		CollectionBondSet { collection: T::CollectionId, amount: DepositBalanceOf<T, I> },
+		/// The bond of a `collection` was slashed by `amount`.
+		CollectionBondSlashed { collection: T::CollectionId, amount: DepositBalanceOf<T, I> },
+		/// The bond of `amount` of a `collection` was returned to its owner.
+		CollectionBondReleased { collection: T::CollectionId, amount: DepositBalanceOf<T, I> },
+		/// A role holder of a `collection` approved the thaw of a frozen `item`, which has
+		/// `approvals` in total.
+		UnlockApprovalAdded {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			approver: T::AccountId,
+			approvals: u32,
+		},
+		/// The `unlock_threshold` of a `collection` was met, so its `item` was thawed.
+		ItemTransferUnlocked { collection: T::CollectionId, item: T::ItemId },
+		/// The `lister` of an `item` paid the listing `fee` of its `collection`.
+		ListingFeePaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			lister: T::AccountId,
+			fee: DepositBalanceOf<T, I>,
+		},
+		/// The approval of a `delegate` was used up by transferring an `item` of `owner`.
+		ApprovalConsumed {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+		},
+		/// The Admin approved the first transfer of an `item`.
+		FirstTransferApproved { collection: T::CollectionId, item: T::ItemId },
+		/// The name of an `item` was set.
+		ItemNameSet {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			name: BoundedVec<u8, T::ItemNameLimit>,
+		},
+		/// An `item` was put into escrow, to be sold to `buyer` for `price`.
+		EscrowOpened {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: ItemPrice<T, I>,
+			expiry: T::BlockNumber,
+		},
+		/// The `buyer` reserved the `price` of an escrowed `item`.
+		EscrowFunded {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			buyer: T::AccountId,
+			price: ItemPrice<T, I>,
+		},
+		/// A party to the escrow of an `item` confirmed the sale.
+		EscrowConfirmed { collection: T::CollectionId, item: T::ItemId, who: T::AccountId },
+		/// The escrowed `item` was sold.
+		EscrowSettled {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: ItemPrice<T, I>,
+		},
+		/// The escrow of an `item` was cancelled and the item returned to the seller.
+		EscrowCancelled { collection: T::CollectionId, item: T::ItemId },
+		/// The escrow of an `item` expired and the item was returned to the seller.
+		EscrowExpired { collection: T::CollectionId, item: T::ItemId },
+		/// Roles were assigned across the collections of `owner`.
+		RolesSet {
+			owner: T::AccountId,
+			assignments: Vec<(T::CollectionId, CollectionRole, T::AccountId)>,
+		},
+		/// The deposit held for an `item` was brought in line with the current parameters.
+		ItemDepositRefreshed {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			old: DepositBalanceOf<T, I>,
+			new: DepositBalanceOf<T, I>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+		/// The given item ID is unknown.
+		UnknownCollection,
+		/// The item ID has already been used for an item.
+		AlreadyExists,
+		/// The owner turned out to be different to what was expected.
+		WrongOwner,
+		/// Invalid witness data given.
+		BadWitness,
+		/// The item ID is already taken.
+		InUse,
+		/// The item or collection is frozen.
+		Frozen,
+		/// The delegate turned out to be different to what was expected.
+		WrongDelegate,
+		/// There is no delegate approved.
+		NoDelegate,
+		/// No approval exists that would allow the transfer.
+		Unapproved,
+		/// The named owner has not signed ownership of the collection is acceptable.
+		Unaccepted,
+		/// The item is locked.
+		Locked,
+		/// All items have been minted.
+		MaxSupplyReached,
+		/// The max supply has already been set.
+		MaxSupplyAlreadySet,
+		/// The provided max supply is less to the amount of items a collection already has.
+		MaxSupplyTooSmall,
 		/// The given item ID is unknown.
 		UnknownItem,
 		/// Item is not for sale.
 		NotForSale,
 		/// The provided bid is too low.
 		BidTooLow,
+		/// The collection does not allow items to be transferred to their current owner.
+		CannotTransferToSelf,
+		/// The item is held in escrow.
+		InEscrow,
+		/// The item is not held in escrow.
+		NoEscrow,
+		/// The escrow has already been funded.
+		AlreadyFunded,
+		/// The escrow has not been funded by the buyer.
+		NotFunded,
+		/// Too many escrows expire in the same block.
+		TooManyEscrows,
+		/// The collection only allows metadata which is a URI pointing to off-chain data.
+		InlineMetadataForbidden,
+		/// The collection doesn't allocate item ids in sequence.
+		SequentialIdsDisabled,
+		/// All item ids of the collection have been allocated.
+		NoAvailableItemId,
+		/// The collection's limit of mints within a single block has been reached.
+		MintRateLimited,
+		/// The item has as many approvals as it may have.
+		TooManyApprovals,
+		/// The approval of the delegate has expired.
+		ApprovalExpired,
+		/// The deposits recorded for a collection don't match the funds reserved for them.
+		DepositMismatch,
+		/// Another item of the collection already carries this name.
+		NameTaken,
+		/// The lock must last at least one block and less than its period.
+		InvalidLockPeriod,
+		/// As many collections as allowed have a recurring lock.
+		TooManyRecurringLocks,
+		/// The collection has no recurring lock.
+		NoRecurringLock,
+		/// The configuration of the collection is locked.
+		SettingsLocked,
+		/// The item id is reserved for the Issuer of the collection.
+		ReservedItemId,
+		/// An item may not be wrapped inside itself.
+		WrapIntoSelf,
+		/// The item is wrapped inside another item.
+		Wrapped,
+		/// The item is not wrapped inside another item.
+		NotWrapped,
+		/// Other items are wrapped inside the item.
+		HasWrappedItems,
+		/// The first transfer of the item has not been approved by the Admin.
+		FirstTransferNotApproved,
+		/// More items were queried at once than `MaxItemsPerQuery`.
+		TooManyItems,
+		/// The collection has already been revealed.
+		AlreadyRevealed,
+		/// The metadata root does not match the commitment of the collection.
+		RevealMismatch,
+		/// The collection has already committed to a metadata root.
+		AlreadyCommitted,
+		/// The marketplace policy of the collection does not allow the delegate to be approved.
+		MarketplaceNotAllowed,
+		/// The receiver does not hold an item of the membership collection.
+		DestinationNotMember,
+		/// The collection already holds as many attributes as its configuration allows.
+		CollectionAttributeLimitReached,
+		/// The item is soulbound and can't be thawed.
+		ItemSoulbound,
+		/// The metadata of the item was updated too recently to be updated again.
+		MetadataUpdateTooSoon,
+		/// The item id doesn't have the parity required by the collection.
+		WrongIdParity,
+		/// The bond of a collection can't be lowered, only released as a whole.
+		BondTooLow,
+		/// The collection has no bond.
+		NoBond,
+		/// The bond of the collection was posted or slashed too recently to be released.
+		BondLocked,
+		/// The sender is not approved to transfer the item.
+		NotApproved,
+		/// The account minted an item of the collection too recently to mint another.
+		MintCooldown,
+		/// The item was minted too long ago for its metadata to be updated.
+		MetadataWindowClosed,
+		/// The item doesn't carry the attribute required for it to be burned.
+		BurnConditionNotMet,
+		/// The collection's limit of distinct owners has been reached.
+		TooManyOwners,
+		/// The provenance of a mint doesn't fit into the limits of attribute keys and values.
+		ProvenanceTooLong,
+		/// The item was acquired too recently to be transferred.
+		HoldingPeriodActive,
+		/// The collection requires several role holders to approve the thaw of an item.
+		UnlockNotApproved,
+		/// The thaw of the item was approved by this account already.
+		UnlockAlreadyApproved,
+		/// The item isn't frozen.
+		NotFrozen,
+		/// The unlock threshold is higher than the number of roles of a collection.
+		InvalidUnlockThreshold,
+		/// The collection allocates its item ids in sequence, see `mint_next`.
+		SequentialIdsEnabled,
+		/// The collection already has items.
+		CollectionNotEmpty,
+		/// The collection lets the Admin thaw items alone, see `unlock_threshold`.
+		NoUnlockThreshold,
+		/// Items of the collection have names already.
+		ItemsNamed,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// the counters of the previous block are no longer relevant.
+			let cleared = MintsThisBlockOf::<T, I>::clear(u32::MAX, None).loops;
+			T::DbWeight::get()
+				.writes(cleared.saturating_add(1).into())
+				.saturating_add(Self::expire_escrows(now))
+				.saturating_add(Self::release_mint_stakes(now))
+				.saturating_add(Self::cycle_recurring_locks(now))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -429,6 +1128,159 @@ pub mod pallet {
 		pub fn collection_owner(collection: T::CollectionId) -> Option<T::AccountId> {
 			Collection::<T, I>::get(collection).map(|i| i.owner)
 		}
+
+		/// Returns `true` if `who` is the owner of `collection`.
+		pub fn is_collection_owner(collection: T::CollectionId, who: &T::AccountId) -> bool {
+			Collection::<T, I>::get(collection).map_or(false, |c| &c.owner == who)
+		}
+
+		/// Get the canonical representation of `collection`, as exposed to other chains.
+		pub fn canonical_collection_id(collection: T::CollectionId) -> Option<u128> {
+			T::CollectionIdConverter::to_canonical(collection)
+		}
+
+		/// Get the collection id represented by `canonical`, if any.
+		pub fn collection_from_canonical(canonical: u128) -> Option<T::CollectionId> {
+			T::CollectionIdConverter::from_canonical(canonical)
+		}
+
+		/// Get the funds locked against the item, which is zero if nothing is locked.
+		///
+		/// This is the price reserved by the buyer of a funded escrow.
+		pub fn item_locked_funds(collection: T::CollectionId, item: T::ItemId) -> ItemPrice<T, I> {
+			EscrowsOf::<T, I>::get(collection, item)
+				.filter(|escrow| escrow.funded)
+				.map_or_else(Zero::zero, |escrow| escrow.price)
+		}
+
+		/// Get the listing and the open escrow of the item, if there are any.
+		pub fn item_trading_state(
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> ItemTradingState<T::AccountId, ItemPrice<T, I>> {
+			ItemTradingState {
+				listing: ItemPriceOf::<T, I>::get(collection, item),
+				escrow: EscrowsOf::<T, I>::get(collection, item)
+					.map(|escrow| (escrow.buyer, escrow.price)),
+			}
+		}
+
+		/// Get the owners of `items`, in the same order, with `None` for the unknown ones.
+		pub fn owners_of(
+			collection: T::CollectionId,
+			items: Vec<T::ItemId>,
+		) -> Result<Vec<(T::ItemId, Option<T::AccountId>)>, DispatchError> {
+			ensure!(
+				items.len() <= T::MaxItemsPerQuery::get() as usize,
+				Error::<T, I>::TooManyItems
+			);
+			Ok(items.into_iter().map(|item| (item, Self::owner(collection, item))).collect())
+		}
+
+		/// Get the number of times the item has been transferred since it was minted.
+		pub fn item_transfer_count(collection: T::CollectionId, item: T::ItemId) -> u32 {
+			TransferCountOf::<T, I>::get(collection, item)
+		}
+
+		/// Returns `true` if `delegate` is approved to transfer the item as of the current block.
+		///
+		/// Expired approvals count as revoked, even if they are still stored.
+		pub fn is_approved(
+			collection: T::CollectionId,
+			item: T::ItemId,
+			delegate: &T::AccountId,
+		) -> bool {
+			Item::<T, I>::get(collection, item)
+				.map_or(false, |details| Self::check_approval(&details, delegate).is_ok())
+		}
+
+		/// Get the SCALE encoded configuration of the collection, exactly as it is stored.
+		///
+		/// Returns `None` if nothing is stored, i.e. the collection has the default configuration
+		/// or doesn't exist.
+		pub fn collection_config_raw(collection: T::CollectionId) -> Option<Vec<u8>> {
+			frame_support::storage::unhashed::get_raw(&CollectionConfigOf::<T, I>::hashed_key_for(
+				collection,
+			))
+		}
+
+		/// Get the id `mint_next` would allocate next within `collection`.
+		///
+		/// Returns `None` unless the collection has `SequentialIds` switched on.
+		pub fn next_item_id(collection: T::CollectionId) -> Option<T::ItemId> {
+			if !Self::is_collection_setting_enabled(&collection, CollectionSetting::SequentialIds) {
+				return None
+			}
+			Self::sequential_item_id(collection).ok()
+		}
+
+		/// Get the metadata of the item.
+		///
+		/// Until the collection is revealed, items without metadata of their own show the
+		/// collection's unrevealed placeholder.
+		pub fn item_metadata(collection: T::CollectionId, item: T::ItemId) -> Option<Vec<u8>> {
+			ItemMetadataOf::<T, I>::get(collection, item)
+				.map(|metadata| metadata.data)
+				.or_else(|| {
+					Item::<T, I>::contains_key(collection, item)
+						.then(|| UnrevealedMetadataOf::<T, I>::get(collection))
+						.flatten()
+				})
+				.map(|data| data.into())
+		}
+
+		/// Get the block in which the item was minted.
+		///
+		/// Returns `None` for items minted before mint blocks were recorded.
+		pub fn item_minted_at(
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> Option<T::BlockNumber> {
+			ItemMintedAt::<T, I>::get(collection, item)
+		}
+
+		/// Get the proof of the storage deal pinning the metadata of the item, if there is one.
+		pub fn item_storage_proof(collection: T::CollectionId, item: T::ItemId) -> Option<Vec<u8>> {
+			ItemMetadataOf::<T, I>::get(collection, item)
+				.and_then(|metadata| metadata.storage_proof)
+				.map(|proof| proof.into())
+		}
+
+		/// Get the on-chain name of the item, if it has one.
+		pub fn item_name(collection: T::CollectionId, item: T::ItemId) -> Option<Vec<u8>> {
+			ItemNameOf::<T, I>::get(collection, item).map(|name| name.into())
+		}
+
+		/// Get the item carrying `name`, within a collection with `UniqueItemNames`.
+		pub fn item_by_name(collection: T::CollectionId, name: Vec<u8>) -> Option<T::ItemId> {
+			let name = BoundedVec::<u8, T::ItemNameLimit>::try_from(name).ok()?;
+			ItemByNameOf::<T, I>::get(collection, name)
+		}
+
+		/// Returns `true` if `who` owns at least one item of `collection`.
+		///
+		/// This and [`Self::count_in_collection`] are the supported way for other pallets to base
+		/// voting or staking weight on the items an account holds.
+		pub fn owns_any_in_collection(who: &T::AccountId, collection: T::CollectionId) -> bool {
+			Account::<T, I>::iter_key_prefix((who, collection)).next().is_some()
+		}
+
+		/// Get the account which may reclaim any item of `collection`, if it has one.
+		pub fn recovery_account(collection: T::CollectionId) -> Option<T::AccountId> {
+			CollectionConfigOf::<T, I>::get(collection).recovery_account
+		}
+
+		/// Get the number of distinct accounts holding at least one item of `collection`.
+		pub fn distinct_owners(collection: T::CollectionId) -> u32 {
+			DistinctOwnersCountOf::<T, I>::get(collection)
+		}
+
+		/// Get the number of items of `collection` owned by `who`.
+		///
+		/// NOTE: this invokes a storage read per item owned.
+		pub fn count_in_collection(who: &T::AccountId, collection: T::CollectionId) -> u32 {
+			Account::<T, I>::iter_key_prefix((who, collection)).count() as u32
+		}
 	}
 
 	#[pallet::call]
@@ -462,7 +1314,7 @@ pub mod pallet {
 				collection,
 				owner.clone(),
 				admin.clone(),
-				T::CollectionDeposit::get(),
+				Self::collection_deposit(&owner),
 				false,
 				Event::Created { collection, creator: owner, owner: admin },
 			)
@@ -547,6 +1399,7 @@ pub mod pallet {
 		/// Mint an item of a particular collection.
 		///
 		/// The origin must be Signed and the sender must be the Issuer of the `collection`.
+		/// Collections with `SequentialIds` are minted through `mint_next` instead.
 		///
 		/// - `collection`: The collection of the item to be minted.
 		/// - `item`: The item value of the item to be minted.
@@ -565,7 +1418,7 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			let owner = T::Lookup::lookup(owner)?;
 
-			Self::do_mint(collection, item, owner, |collection_details| {
+			Self::do_mint(collection, item, owner, Some(origin.clone()), |collection_details| {
 				ensure!(collection_details.issuer == origin, Error::<T, I>::NoPermission);
 				Ok(())
 			})
@@ -573,7 +1426,8 @@ pub mod pallet {
 
 		/// Destroy a single item.
 		///
-		/// Origin must be Signed and the sender should be the Admin of the `collection`.
+		/// Origin must be Signed and the sender should be the Admin of the `collection` or the
+		/// Owner of the `item`, unless the `burn_policy` of the collection permits others.
 		///
 		/// - `collection`: The collection of the item to be burned.
 		/// - `item`: The item of the item to be burned.
@@ -594,9 +1448,9 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			let check_owner = check_owner.map(T::Lookup::lookup).transpose()?;
 
-			Self::do_burn(collection, item, |collection_details, details| {
-				let is_permitted = collection_details.admin == origin || details.owner == origin;
-				ensure!(is_permitted, Error::<T, I>::NoPermission);
+			Self::do_burn(collection, item, Some(origin.clone()), |collection_details, details| {
+				let config = CollectionConfigOf::<T, I>::get(&collection);
+				Self::ensure_burn_allowed(&config, collection_details, details, &origin)?;
 				ensure!(
 					check_owner.map_or(true, |o| o == details.owner),
 					Error::<T, I>::WrongOwner
@@ -607,19 +1461,23 @@ pub mod pallet {
 
 		/// Move an item from the sender account to another.
 		///
-		/// This resets the approved account of the item.
+		/// This resets the approvals of the item.
 		///
 		/// Origin must be Signed and the signing account must be either:
 		/// - the Admin of the `collection`;
 		/// - the Owner of the `item`;
-		/// - the approved delegate for the `item` (in this case, the approval is reset).
+		/// - an approved delegate for the `item` whose approval hasn't expired.
+		///
+		/// If `dest` is the `burn_on_transfer_to` account of the collection, the item is burned
+		/// instead, without checking the transfer restrictions of the collection. The
+		/// `TransferPolicy` may still veto it.
 		///
 		/// Arguments:
 		/// - `collection`: The collection of the item to be transferred.
 		/// - `item`: The item of the item to be transferred.
 		/// - `dest`: The account to receive ownership of the item.
 		///
-		/// Emits `Transferred`.
+		/// Emits `Transferred`, or `Burned` if the item was transferred to the sink.
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::transfer())]
@@ -634,11 +1492,12 @@ pub mod pallet {
 
 			Self::do_transfer(collection, item, dest, |collection_details, details| {
 				if details.owner != origin && collection_details.admin != origin {
-					let approved = details.approved.take().map_or(false, |i| i == origin);
-					ensure!(approved, Error::<T, I>::NoPermission);
+					Self::check_approval(details, &origin)?;
 				}
 				Ok(())
-			})
+			})?;
+			Self::freeze_after_transfer(collection, item);
+			Ok(())
 		}
 
 		/// Reevaluate the deposits on some items.
@@ -668,11 +1527,8 @@ pub mod pallet {
 
 			let mut collection_details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
-			ensure!(collection_details.owner == origin, Error::<T, I>::NoPermission);
-			let deposit = match collection_details.free_holding {
-				true => Zero::zero(),
-				false => T::ItemDeposit::get(),
-			};
+			ensure!(Self::is_collection_owner(collection, &origin), Error::<T, I>::NoPermission);
+			let deposit = Self::item_deposit(&collection_details);
 
 			let mut successful = Vec::with_capacity(items.len());
 			for item in items.into_iter() {
@@ -725,24 +1581,16 @@ pub mod pallet {
 			item: T::ItemId,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-
-			let mut details =
-				Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
-			let collection_details =
-				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
-			ensure!(collection_details.freezer == origin, Error::<T, I>::NoPermission);
-
-			details.is_frozen = true;
-			Item::<T, I>::insert(&collection, &item, &details);
-
-			Self::deposit_event(Event::<T, I>::Frozen { collection, item });
-			Ok(())
+			Self::do_freeze_item(collection, item, Some(origin)).map(|_| ())
 		}
 
 		/// Re-allow unprivileged transfer of an item.
 		///
 		/// Origin must be Signed and the sender should be the Freezer of the `collection`.
 		///
+		/// Fails with `UnlockNotApproved` if the collection has an `unlock_threshold` above one,
+		/// in which case items are thawed through `approve_unlock`.
+		///
 		/// - `collection`: The collection of the item to be thawed.
 		/// - `item`: The item of the item to be thawed.
 		///
@@ -756,18 +1604,7 @@ pub mod pallet {
 			item: T::ItemId,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-
-			let mut details =
-				Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
-			let collection_details =
-				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
-			ensure!(collection_details.admin == origin, Error::<T, I>::NoPermission);
-
-			details.is_frozen = false;
-			Item::<T, I>::insert(&collection, &item, &details);
-
-			Self::deposit_event(Event::<T, I>::Thawed { collection, item });
-			Ok(())
+			Self::do_thaw_item(collection, item, Some(origin)).map(|_| ())
 		}
 
 		/// Disallow further unprivileged transfers for a whole collection.
@@ -785,16 +1622,7 @@ pub mod pallet {
 			collection: T::CollectionId,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-
-			Collection::<T, I>::try_mutate(collection, |maybe_details| {
-				let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
-				ensure!(origin == details.freezer, Error::<T, I>::NoPermission);
-
-				details.is_frozen = true;
-
-				Self::deposit_event(Event::<T, I>::CollectionFrozen { collection });
-				Ok(())
-			})
+			Self::do_freeze_collection(collection, Some(origin)).map(|_| ())
 		}
 
 		/// Re-allow unprivileged transfers for a whole collection.
@@ -812,16 +1640,7 @@ pub mod pallet {
 			collection: T::CollectionId,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-
-			Collection::<T, I>::try_mutate(collection, |maybe_details| {
-				let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
-				ensure!(origin == details.admin, Error::<T, I>::NoPermission);
-
-				details.is_frozen = false;
-
-				Self::deposit_event(Event::<T, I>::CollectionThawed { collection });
-				Ok(())
-			})
+			Self::do_thaw_collection(collection, Some(origin)).map(|_| ())
 		}
 
 		/// Change the Owner of a collection.
@@ -861,8 +1680,15 @@ pub mod pallet {
 					details.total_deposit,
 					Reserved,
 				)?;
-				CollectionAccount::<T, I>::remove(&details.owner, &collection);
-				CollectionAccount::<T, I>::insert(&owner, &collection, ());
+				if let Some(bond) = CollectionBondOf::<T, I>::get(&collection) {
+					T::Currency::repatriate_reserved(
+						&details.owner,
+						&owner,
+						bond.amount,
+						Reserved,
+					)?;
+				}
+				Self::move_collection_account(collection, &details.owner, &owner);
 				details.owner = owner.clone();
 				OwnershipAcceptance::<T, I>::remove(&owner);
 
@@ -917,8 +1743,13 @@ pub mod pallet {
 		/// - `collection`: The collection of the item to be approved for delegated transfer.
 		/// - `item`: The item of the item to be approved for delegated transfer.
 		/// - `delegate`: The account to delegate permission to transfer the item.
+		/// - `maybe_deadline`: If `Some`, the number of blocks after which the approval expires.
+		///   Defaults to, and is capped at, the `approval_ttl` of the collection, if it has one.
+		///
+		/// An item may have up to `MaxApprovalsPerItem` approvals, expired approvals don't count
+		/// towards this limit. Approving an already approved `delegate` replaces its deadline.
 		///
-		/// Important NOTE: The `approved` account gets reset after each transfer.
+		/// Important NOTE: All approvals get reset after each transfer.
 		///
 		/// Emits `ApprovedTransfer` on success.
 		///
@@ -929,6 +1760,7 @@ pub mod pallet {
 			collection: T::CollectionId,
 			item: T::ItemId,
 			delegate: AccountIdLookupOf<T>,
+			maybe_deadline: Option<T::BlockNumber>,
 		) -> DispatchResult {
 			let maybe_check: Option<T::AccountId> = T::ForceOrigin::try_origin(origin)
 				.map(|_| None)
@@ -945,22 +1777,36 @@ pub mod pallet {
 				let permitted = check == collection_details.admin || check == details.owner;
 				ensure!(permitted, Error::<T, I>::NoPermission);
 			}
+			Self::ensure_marketplace_allowed(&collection, &delegate)?;
 
-			details.approved = Some(delegate);
+			let now = frame_system::Pallet::<T>::block_number();
+			let maybe_deadline = match CollectionConfigOf::<T, I>::get(&collection).approval_ttl {
+				Some(ttl) => Some(maybe_deadline.map_or(ttl, |d| d.min(ttl))),
+				None => maybe_deadline,
+			};
+			let deadline = maybe_deadline.map(|d| d.saturating_add(now));
+			// expired approvals no longer take up a slot.
+			details.approvals.retain(|_, deadline| deadline.map_or(true, |d| d >= now));
+			details
+				.approvals
+				.try_insert(delegate.clone(), deadline)
+				.map_err(|_| Error::<T, I>::TooManyApprovals)?;
 			Item::<T, I>::insert(&collection, &item, &details);
 
-			let delegate = details.approved.expect("set as Some above; qed");
-			Self::deposit_event(Event::ApprovedTransfer {
-				collection,
-				item,
-				owner: details.owner,
-				delegate,
-			});
+			if !Self::is_event_muted(&collection, EventCategory::Transfers) {
+				Self::deposit_event(Event::ApprovedTransfer {
+					collection,
+					item,
+					owner: details.owner,
+					delegate,
+					deadline,
+				});
+			}
 
 			Ok(())
 		}
 
-		/// Cancel the prior approval for the transfer of an item by a delegate.
+		/// Cancel prior approvals for the transfer of an item by delegates.
 		///
 		/// Origin must be either:
 		/// - the `Force` origin;
@@ -970,10 +1816,10 @@ pub mod pallet {
 		/// Arguments:
 		/// - `collection`: The collection of the item of whose approval will be cancelled.
 		/// - `item`: The item of the item of whose approval will be cancelled.
-		/// - `maybe_check_delegate`: If `Some` will ensure that the given account is the one to
-		///   which permission of transfer is delegated.
+		/// - `maybe_check_delegate`: If `Some`, only the approval of the given account is
+		///   cancelled, failing if it has none. Otherwise all approvals of the item are cancelled.
 		///
-		/// Emits `ApprovalCancelled` on success.
+		/// Emits `ApprovalCancelled` for every cancelled approval on success.
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::cancel_approval())]
@@ -996,18 +1842,31 @@ pub mod pallet {
 				ensure!(permitted, Error::<T, I>::NoPermission);
 			}
 			let maybe_check_delegate = maybe_check_delegate.map(T::Lookup::lookup).transpose()?;
-			let old = details.approved.take().ok_or(Error::<T, I>::NoDelegate)?;
-			if let Some(check_delegate) = maybe_check_delegate {
-				ensure!(check_delegate == old, Error::<T, I>::WrongDelegate);
-			}
+			ensure!(!details.approvals.is_empty(), Error::<T, I>::NoDelegate);
+			let cancelled = match maybe_check_delegate {
+				Some(check_delegate) => {
+					details
+						.approvals
+						.remove(&check_delegate)
+						.ok_or(Error::<T, I>::WrongDelegate)?;
+					vec![check_delegate]
+				},
+				None =>
+					sp_std::mem::take(&mut details.approvals).into_inner().into_keys().collect(),
+			};
 
 			Item::<T, I>::insert(&collection, &item, &details);
-			Self::deposit_event(Event::ApprovalCancelled {
-				collection,
-				item,
-				owner: details.owner,
-				delegate: old,
-			});
+			if Self::is_event_muted(&collection, EventCategory::Transfers) {
+				return Ok(())
+			}
+			for delegate in cancelled {
+				Self::deposit_event(Event::ApprovalCancelled {
+					collection,
+					item,
+					owner: details.owner.clone(),
+					delegate,
+				});
+			}
 
 			Ok(())
 		}
@@ -1052,8 +1911,7 @@ pub mod pallet {
 				item.free_holding = free_holding;
 				item.is_frozen = is_frozen;
 				*maybe_item = Some(item);
-				CollectionAccount::<T, I>::remove(&old_owner, &collection);
-				CollectionAccount::<T, I>::insert(&new_owner, &collection, ());
+				Self::move_collection_account(collection, &old_owner, &new_owner);
 
 				Self::deposit_event(Event::ItemStatusChanged { collection });
 				Ok(())
@@ -1066,8 +1924,9 @@ pub mod pallet {
 		/// `collection`.
 		///
 		/// If the origin is Signed, then funds of signer are reserved according to the formula:
-		/// `MetadataDepositBase + DepositPerByte * (key.len + value.len)` taking into
-		/// account any already reserved funds.
+		/// `AttributeDepositBase + DepositPerByte * (key.len + value.len)` taking into
+		/// account any already reserved funds. No deposit is taken if the collection has the
+		/// `FreeAttributes` setting enabled.
 		///
 		/// - `collection`: The identifier of the collection whose item's metadata to set.
 		/// - `maybe_item`: The identifier of the item whose metadata to set.
@@ -1092,8 +1951,12 @@ pub mod pallet {
 			let mut collection_details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
+				ensure!(
+					Self::is_collection_owner(collection, check_owner),
+					Error::<T, I>::NoPermission
+				);
 			}
+			Self::ensure_content_writable(&collection, &maybe_check_owner)?;
 			let maybe_is_frozen = match maybe_item {
 				None => CollectionMetadataOf::<T, I>::get(collection).map(|v| v.is_frozen),
 				Some(item) => ItemMetadataOf::<T, I>::get(collection, item).map(|v| v.is_frozen),
@@ -1102,12 +1965,15 @@ pub mod pallet {
 
 			let attribute = Attribute::<T, I>::get((collection, maybe_item, &key));
 			if attribute.is_none() {
+				Self::ensure_attribute_allowed(&collection, &collection_details)?;
 				collection_details.attributes.saturating_inc();
 			}
 			let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
 			collection_details.total_deposit.saturating_reduce(old_deposit);
+			let free_attributes =
+				Self::is_collection_setting_enabled(&collection, CollectionSetting::FreeAttributes);
 			let mut deposit = Zero::zero();
-			if !collection_details.free_holding && maybe_check_owner.is_some() {
+			if !collection_details.free_holding && !free_attributes && maybe_check_owner.is_some() {
 				deposit = T::DepositPerByte::get()
 					.saturating_mul(((key.len() + value.len()) as u32).into())
 					.saturating_add(T::AttributeDepositBase::get());
@@ -1121,7 +1987,10 @@ pub mod pallet {
 
 			Attribute::<T, I>::insert((&collection, maybe_item, &key), (&value, deposit));
 			Collection::<T, I>::insert(collection, &collection_details);
-			Self::deposit_event(Event::AttributeSet { collection, maybe_item, key, value });
+			if maybe_item.is_none() || !Self::is_event_muted(&collection, EventCategory::Attributes)
+			{
+				Self::deposit_event(Event::AttributeSet { collection, maybe_item, key, value });
+			}
 			Ok(())
 		}
 
@@ -1153,8 +2022,12 @@ pub mod pallet {
 			let mut collection_details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
+				ensure!(
+					Self::is_collection_owner(collection, check_owner),
+					Error::<T, I>::NoPermission
+				);
 			}
+			Self::ensure_content_writable(&collection, &maybe_check_owner)?;
 			let maybe_is_frozen = match maybe_item {
 				None => CollectionMetadataOf::<T, I>::get(collection).map(|v| v.is_frozen),
 				Some(item) => ItemMetadataOf::<T, I>::get(collection, item).map(|v| v.is_frozen),
@@ -1166,7 +2039,11 @@ pub mod pallet {
 				collection_details.total_deposit.saturating_reduce(deposit);
 				T::Currency::unreserve(&collection_details.owner, deposit);
 				Collection::<T, I>::insert(collection, &collection_details);
-				Self::deposit_event(Event::AttributeCleared { collection, maybe_item, key });
+				if maybe_item.is_none() ||
+					!Self::is_event_muted(&collection, EventCategory::Attributes)
+				{
+					Self::deposit_event(Event::AttributeCleared { collection, maybe_item, key });
+				}
 			}
 			Ok(())
 		}
@@ -1174,16 +2051,24 @@ pub mod pallet {
 		/// Set the metadata for an item.
 		///
 		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
-		/// `collection`.
+		/// `collection`, or its Issuer if the collection has `IssuerManagesMetadata` switched on.
 		///
 		/// If the origin is Signed, then funds of signer are reserved according to the formula:
-		/// `MetadataDepositBase + DepositPerByte * data.len` taking into
+		/// `MetadataDepositBase + DepositPerByte * (data.len + storage_proof.len)` taking into
 		/// account any already reserved funds.
 		///
 		/// - `collection`: The identifier of the collection whose item's metadata to set.
 		/// - `item`: The identifier of the item whose metadata to set.
 		/// - `data`: The general information of this item. Limited in length by `StringLimit`.
-		/// - `is_frozen`: Whether the metadata should be frozen against further changes.
+		/// - `is_frozen`: Whether the metadata should be frozen against further changes. Always the
+		///   case if the collection has `MetadataWriteOnce` switched on.
+		/// - `storage_proof`: The proof of a storage deal pinning `data`, if any. Limited in length
+		///   by `StringLimit` and not verified on-chain.
+		///
+		/// Unless the origin is `ForceOrigin`, fails with `MetadataUpdateTooSoon` if fewer than the
+		/// `metadata_update_cooldown` blocks of the collection passed since the last update, and
+		/// with `MetadataWindowClosed` if more than its `metadata_mutable_until_age` blocks passed
+		/// since the item was minted.
 		///
 		/// Emits `MetadataSet`.
 		///
@@ -1195,6 +2080,7 @@ pub mod pallet {
 			item: T::ItemId,
 			data: BoundedVec<u8, T::StringLimit>,
 			is_frozen: bool,
+			storage_proof: Option<BoundedVec<u8, T::StringLimit>>,
 		) -> DispatchResult {
 			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
 				.map(|_| None)
@@ -1204,8 +2090,17 @@ pub mod pallet {
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 
 			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
+				Self::ensure_metadata_writer(&collection, &collection_details, check_owner)?;
 			}
+			Self::ensure_content_writable(&collection, &maybe_check_owner)?;
+			Self::ensure_metadata_allowed(&collection, &data)?;
+			Self::ensure_metadata_update_allowed(&collection, &item, &maybe_check_owner)?;
+			Self::ensure_metadata_window_open(&collection, &item, &maybe_check_owner)?;
+			let is_frozen = is_frozen ||
+				Self::is_collection_setting_enabled(
+					&collection,
+					CollectionSetting::MetadataWriteOnce,
+				);
 
 			ItemMetadataOf::<T, I>::try_mutate_exists(collection, item, |metadata| {
 				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
@@ -1218,8 +2113,9 @@ pub mod pallet {
 				collection_details.total_deposit.saturating_reduce(old_deposit);
 				let mut deposit = Zero::zero();
 				if !collection_details.free_holding && maybe_check_owner.is_some() {
+					let proof_len = storage_proof.as_ref().map_or(0, |proof| proof.len());
 					deposit = T::DepositPerByte::get()
-						.saturating_mul(((data.len()) as u32).into())
+						.saturating_mul(((data.len() + proof_len) as u32).into())
 						.saturating_add(T::MetadataDepositBase::get());
 				}
 				if deposit > old_deposit {
@@ -1229,10 +2125,28 @@ pub mod pallet {
 				}
 				collection_details.total_deposit.saturating_accrue(deposit);
 
-				*metadata = Some(ItemMetadata { deposit, data: data.clone(), is_frozen });
+				*metadata = Some(ItemMetadata {
+					deposit,
+					data: data.clone(),
+					is_frozen,
+					storage_proof: storage_proof.clone(),
+				});
 
 				Collection::<T, I>::insert(&collection, &collection_details);
-				Self::deposit_event(Event::MetadataSet { collection, item, data, is_frozen });
+				LastMetadataUpdateOf::<T, I>::insert(
+					&collection,
+					&item,
+					frame_system::Pallet::<T>::block_number(),
+				);
+				if !Self::is_event_muted(&collection, EventCategory::Metadata) {
+					Self::deposit_event(Event::MetadataSet {
+						collection,
+						item,
+						data,
+						is_frozen,
+						storage_proof,
+					});
+				}
 				Ok(())
 			})
 		}
@@ -1240,7 +2154,7 @@ pub mod pallet {
 		/// Clear the metadata for an item.
 		///
 		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
-		/// `item`.
+		/// `item`, or the Issuer of the collection if it has `IssuerManagesMetadata` switched on.
 		///
 		/// Any deposit is freed for the collection's owner.
 		///
@@ -1263,8 +2177,9 @@ pub mod pallet {
 			let mut collection_details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
+				Self::ensure_metadata_writer(&collection, &collection_details, check_owner)?;
 			}
+			Self::ensure_content_writable(&collection, &maybe_check_owner)?;
 
 			ItemMetadataOf::<T, I>::try_mutate_exists(collection, item, |metadata| {
 				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
@@ -1278,7 +2193,9 @@ pub mod pallet {
 				collection_details.total_deposit.saturating_reduce(deposit);
 
 				Collection::<T, I>::insert(&collection, &collection_details);
-				Self::deposit_event(Event::MetadataCleared { collection, item });
+				if !Self::is_event_muted(&collection, EventCategory::Metadata) {
+					Self::deposit_event(Event::MetadataCleared { collection, item });
+				}
 				Ok(())
 			})
 		}
@@ -1286,7 +2203,8 @@ pub mod pallet {
 		/// Set the metadata for a collection.
 		///
 		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
-		/// the `collection`.
+		/// the `collection`, or its Issuer if the collection has `IssuerManagesMetadata` switched
+		/// on.
 		///
 		/// If the origin is `Signed`, then funds of signer are reserved according to the formula:
 		/// `MetadataDepositBase + DepositPerByte * data.len` taking into
@@ -1294,9 +2212,12 @@ pub mod pallet {
 		///
 		/// - `collection`: The identifier of the item whose metadata to update.
 		/// - `data`: The general information of this item. Limited in length by `StringLimit`.
-		/// - `is_frozen`: Whether the metadata should be frozen against further changes.
+		/// - `is_frozen`: Whether the metadata should be frozen against further changes. Setting it
+		///   sets and freezes the metadata in a single call, e.g. to finalize the branding of a
+		///   collection.
 		///
-		/// Emits `CollectionMetadataSet`.
+		/// Emits `CollectionMetadataSet`, whose `is_frozen` tells whether the metadata is now
+		/// frozen.
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::set_collection_metadata())]
@@ -1313,8 +2234,10 @@ pub mod pallet {
 			let mut details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+				Self::ensure_metadata_writer(&collection, &details, check_owner)?;
 			}
+			Self::ensure_content_writable(&collection, &maybe_check_owner)?;
+			Self::ensure_metadata_allowed(&collection, &data)?;
 
 			CollectionMetadataOf::<T, I>::try_mutate_exists(collection, |metadata| {
 				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
@@ -1347,7 +2270,8 @@ pub mod pallet {
 		/// Clear the metadata for a collection.
 		///
 		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
-		/// the `collection`.
+		/// the `collection`, or its Issuer if the collection has `IssuerManagesMetadata` switched
+		/// on.
 		///
 		/// Any deposit is freed for the collection's owner.
 		///
@@ -1365,11 +2289,12 @@ pub mod pallet {
 				.map(|_| None)
 				.or_else(|origin| ensure_signed(origin).map(Some))?;
 
-			let details =
+			let mut details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+				Self::ensure_metadata_writer(&collection, &details, check_owner)?;
 			}
+			Self::ensure_content_writable(&collection, &maybe_check_owner)?;
 
 			CollectionMetadataOf::<T, I>::try_mutate_exists(collection, |metadata| {
 				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
@@ -1377,6 +2302,8 @@ pub mod pallet {
 
 				let deposit = metadata.take().ok_or(Error::<T, I>::UnknownCollection)?.deposit;
 				T::Currency::unreserve(&details.owner, deposit);
+				details.total_deposit.saturating_reduce(deposit);
+				Collection::<T, I>::insert(&collection, &details);
 				Self::deposit_event(Event::CollectionMetadataCleared { collection });
 				Ok(())
 			})
@@ -1446,7 +2373,10 @@ pub mod pallet {
 			let details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+				ensure!(
+					Self::is_collection_owner(collection, check_owner),
+					Error::<T, I>::NoPermission
+				);
 			}
 
 			ensure!(details.items <= max_supply, Error::<T, I>::MaxSupplyTooSmall);
@@ -1465,6 +2395,10 @@ pub mod pallet {
 		/// - `price`: The price for the item. Pass `None`, to reset the price.
 		/// - `buyer`: Restricts the buy operation to a specific account.
 		///
+		/// If the collection has a `listing_fee`, it is charged from the sender when an item which
+		/// isn't listed yet gets a price. Nothing is changed if the fee can't be paid.
+		///
+		/// Emits `ListingFeePaid` if a listing fee was charged.
 		/// Emits `ItemPriceSet` on success if the price is not `None`.
 		/// Emits `ItemPriceRemoved` on success if the price is `None`.
 		#[pallet::weight(T::WeightInfo::set_price())]
@@ -1500,5 +2434,767 @@ pub mod pallet {
 			let origin = ensure_signed(origin)?;
 			Self::do_buy_item(collection, item, origin, bid_price)
 		}
+
+		/// Set the configuration of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`. Only `ForceOrigin` may switch `FreeAttributes` on or off, or change
+		/// the configuration once `SettingsLocked` is switched on, or set the `recovery_account`
+		/// or change the `unlock_threshold` once the collection has items. `Revealed` can't be
+		/// changed, collections are revealed through `reveal_collection`.
+		///
+		/// - `collection`: The identifier of the collection to configure.
+		/// - `config`: The new configuration of the collection.
+		///
+		/// Emits `CollectionConfigChanged` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_collection_config())]
+		pub fn set_collection_config(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			config: CollectionConfigFor<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_set_collection_config(collection, config, maybe_check_owner)
+		}
+
+		/// Put an item into escrow in order to sell it to a single counterparty.
+		///
+		/// Origin must be Signed and must be the owner of the `item`. The item is held by the
+		/// pallet until the escrow is settled, cancelled or expires after `EscrowTimeout` blocks.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be sold.
+		/// - `price`: The amount the buyer has to pay for the item.
+		/// - `counterparty`: The only account which may buy the item.
+		///
+		/// Emits `EscrowOpened` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::open_escrow())]
+		pub fn open_escrow(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			price: ItemPrice<T, I>,
+			counterparty: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let counterparty = T::Lookup::lookup(counterparty)?;
+			Self::do_open_escrow(collection, item, origin, price, counterparty)
+		}
+
+		/// Reserve the price of an escrowed item.
+		///
+		/// Origin must be Signed and must be the counterparty of the escrow.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The escrowed item.
+		///
+		/// Emits `EscrowFunded` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::fund_escrow())]
+		pub fn fund_escrow(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_fund_escrow(collection, item, origin)
+		}
+
+		/// Confirm the sale of an escrowed item.
+		///
+		/// Origin must be Signed and must be either the seller or the buyer. The buyer may only
+		/// confirm once the escrow is funded. Once both have confirmed, the price is paid to the
+		/// seller and the item is transferred to the buyer.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The escrowed item.
+		///
+		/// Emits `EscrowConfirmed`, and `EscrowSettled` once both parties confirmed.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::confirm_escrow())]
+		#[transactional]
+		pub fn confirm_escrow(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_confirm_escrow(collection, item, origin)
+		}
+
+		/// Cancel the escrow of an item, refunding the buyer and returning the item to the
+		/// seller.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be either the
+		/// seller or the buyer.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The escrowed item.
+		///
+		/// Emits `EscrowCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_escrow())]
+		pub fn cancel_escrow(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let maybe_check_party = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_cancel_escrow(collection, item, maybe_check_party)
+		}
+
+		/// Assign roles across several collections at once.
+		///
+		/// Origin must be Signed and the sender should be the Owner of every collection named in
+		/// `assignments`. Nothing is changed unless all assignments are permitted.
+		///
+		/// - `assignments`: The collections, the roles within them, and the accounts to assign them
+		///   to.
+		///
+		/// Emits `RolesSet`.
+		///
+		/// Weight: `O(assignments.len())`
+		#[pallet::weight(T::WeightInfo::set_role_many(assignments.len() as u32))]
+		pub fn set_role_many(
+			origin: OriginFor<T>,
+			assignments: BoundedVec<
+				(T::CollectionId, CollectionRole, AccountIdLookupOf<T>),
+				T::MaxRoleAssignments,
+			>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let assignments = assignments
+				.into_iter()
+				.map(|(collection, role, who)| Ok((collection, role, T::Lookup::lookup(who)?)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+			Self::do_set_role_many(origin, assignments)
+		}
+
+		/// Bring the deposit held for an item in line with the current deposit parameters.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// Any difference to the deposit currently held is reserved from, or unreserved to, the
+		/// owner of the `collection`. Fails if a higher deposit cannot be reserved.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item whose deposit should be refreshed.
+		///
+		/// Emits `ItemDepositRefreshed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::refresh_item_deposit())]
+		pub fn refresh_item_deposit(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_refresh_item_deposit(collection, item, maybe_check_owner)
+		}
+
+		/// Mint the next item of a collection which allocates item ids in sequence.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the `collection`, which
+		/// must have `SequentialIds` switched on. Ids are never reused, even after a burn, and ids
+		/// without the `id_parity` of the collection are skipped.
+		///
+		/// - `collection`: The collection of the item to be minted.
+		/// - `owner`: The initial owner of the minted item.
+		///
+		/// Emits `Issued` with the allocated item id when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::mint_next())]
+		pub fn mint_next(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			owner: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			Self::do_mint_next(collection, owner, Some(origin.clone()), |collection_details| {
+				ensure!(collection_details.issuer == origin, Error::<T, I>::NoPermission);
+				Ok(())
+			})
+			.map(|_| ())
+		}
+
+		/// Set the on-chain name of an item.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`. Within a collection with `UniqueItemNames`, no two items may carry the
+		/// same name.
+		///
+		/// - `collection`: The collection of the item whose name to set.
+		/// - `item`: The item whose name to set.
+		/// - `name`: The name of the item. Limited in length by `ItemNameLimit`.
+		///
+		/// Emits `ItemNameSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_item_name())]
+		pub fn set_item_name(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			name: BoundedVec<u8, T::ItemNameLimit>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_set_item_name(collection, item, name, maybe_check_owner)
+		}
+
+		/// Freeze a collection now and again every `period` blocks, each time for `duration`
+		/// blocks, until the schedule is cancelled.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the `collection`. An
+		/// existing recurring lock of the collection is replaced.
+		///
+		/// - `collection`: The collection to be frozen.
+		/// - `duration`: The number of blocks the collection stays frozen each time.
+		/// - `period`: The number of blocks between the start of two frozen windows.
+		///
+		/// Emits `RecurringLockSet` and `CollectionFrozen`, then `CollectionThawed` and
+		/// `CollectionFrozen` on each cycle.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::lock_collection_recurring())]
+		pub fn lock_collection_recurring(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			duration: T::BlockNumber,
+			period: T::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_lock_collection_recurring(collection, duration, period, origin)
+		}
+
+		/// Stop the recurring lock of a collection. The collection stays as it currently is.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the `collection`.
+		///
+		/// - `collection`: The collection whose recurring lock to cancel.
+		///
+		/// Emits `RecurringLockCancelled`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_recurring_lock())]
+		pub fn cancel_recurring_lock(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_recurring_lock(collection, origin)
+		}
+
+		/// Disallow changing the metadata, attributes and item names of a collection, while
+		/// keeping its items transferable.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`. Only `ForceOrigin` may revert this, by switching `ContentFrozen` off.
+		///
+		/// - `collection`: The collection whose content to freeze.
+		///
+		/// Emits `CollectionContentFrozen`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::freeze_collection_content())]
+		pub fn freeze_collection_content(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_freeze_collection_content(collection, maybe_check_owner)
+		}
+
+		/// Wrap an item inside another item, handing it to an account bound to the parent.
+		///
+		/// Origin must be Signed and the sender should be the Owner of both items. The wrapped
+		/// item can't be transferred or burned until it's unwrapped, and the parent can't be
+		/// burned while items are wrapped inside it. Wrapping and unwrapping only move the item in
+		/// and out of custody, so the transfer restrictions of its collection don't apply.
+		///
+		/// - `parent`: The collection and id of the item to wrap the `child` inside of.
+		/// - `child`: The collection and id of the item to be wrapped.
+		///
+		/// Emits `ItemWrapped`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::wrap_item())]
+		pub fn wrap_item(
+			origin: OriginFor<T>,
+			parent: (T::CollectionId, T::ItemId),
+			child: (T::CollectionId, T::ItemId),
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_wrap_item(parent, child, origin)
+		}
+
+		/// Unwrap an item, handing it to the current Owner of the item it was wrapped inside of.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the parent item.
+		///
+		/// - `child`: The collection and id of the wrapped item.
+		///
+		/// Emits `ItemUnwrapped`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::unwrap_item())]
+		pub fn unwrap_item(
+			origin: OriginFor<T>,
+			child: (T::CollectionId, T::ItemId),
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_unwrap_item(child, origin)
+		}
+
+		/// Approve the first transfer of an item, within a collection with
+		/// `RequireFirstTransferApproval`. Later transfers need no approval.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the `collection`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item whose first transfer to approve.
+		///
+		/// Emits `FirstTransferApproved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_first_transfer())]
+		pub fn approve_first_transfer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_approve_first_transfer(collection, item, origin)
+		}
+
+		/// Set the metadata shown for the items of a collection which have no metadata of their
+		/// own, until the collection is revealed.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// - `collection`: The collection whose placeholder metadata to set.
+		/// - `data`: The placeholder metadata. Limited in length by `StringLimit`.
+		///
+		/// Emits `UnrevealedMetadataSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_unrevealed_metadata())]
+		pub fn set_unrevealed_metadata(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			data: BoundedVec<u8, T::StringLimit>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_set_unrevealed_metadata(collection, data, maybe_check_owner)
+		}
+
+		/// Reveal the items of a collection, dropping its placeholder metadata.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// - `collection`: The collection to reveal.
+		/// - `maybe_metadata_root`: The metadata root the collection committed to through
+		///   `commit_reveal_hash`. Required if, and only checked if, there is a commitment.
+		///
+		/// Emits `CollectionRevealed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::reveal_collection())]
+		pub fn reveal_collection(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_metadata_root: Option<BoundedVec<u8, T::StringLimit>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_reveal_collection(collection, maybe_metadata_root, maybe_check_owner)
+		}
+
+		/// Commit to the hash of the metadata root a collection is going to be revealed with.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`. Only the `ForceOrigin` may replace an existing commitment.
+		///
+		/// - `collection`: The collection to commit for.
+		/// - `hash`: The hash of the metadata root, using the `Hashing` of the runtime.
+		///
+		/// Emits `RevealCommitted`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::commit_reveal_hash())]
+		pub fn commit_reveal_hash(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			hash: T::Hash,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_commit_reveal_hash(collection, hash, maybe_check_owner)
+		}
+
+		/// Set which marketplace operators may be approved to transfer the items of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// Existing approvals are unaffected, the policy only applies to new ones.
+		///
+		/// - `collection`: The collection whose marketplace policy to set.
+		/// - `policy`: Whether any account, only an allowlist, or all but a denylist of accounts
+		///   may be approved.
+		///
+		/// Emits `MarketplacePolicySet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_marketplace_policy())]
+		pub fn set_marketplace_policy(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			policy: MarketplacePolicyFor<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_set_marketplace_policy(collection, policy, maybe_check_owner)
+		}
+
+		/// Move an item to a new owner, regardless of freezes, locks, approvals and the transfer
+		/// restrictions of its collection.
+		///
+		/// Origin must conform to `ForceOrigin`.
+		///
+		/// All approvals of the item are cleared and its listing is removed. Escrowed and wrapped
+		/// items can't be moved.
+		///
+		/// - `collection`: The collection of the item to be transferred.
+		/// - `item`: The item to be transferred.
+		/// - `dest`: The account to receive ownership of the item.
+		///
+		/// Emits `Transferred` followed by `ItemForceTransferred`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_transfer())]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			dest: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_force_transfer(collection, item, dest)
+		}
+
+		/// Permanently disallow unprivileged transfers of an item.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the `collection`.
+		///
+		/// Unlike `freeze`, this can't be reverted by `thaw`. Only the `ForceOrigin` can still move
+		/// the item, through `force_transfer`.
+		///
+		/// - `collection`: The collection of the item to be made soulbound.
+		/// - `item`: The item to be made soulbound.
+		///
+		/// Emits `Frozen` followed by `ItemMadeSoulbound`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::make_item_soulbound())]
+		pub fn make_item_soulbound(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_make_item_soulbound(collection, item, Some(origin))
+		}
+
+		/// Mint an item to the sender and list it for sale right away.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the `collection`. The
+		/// item is minted as by `mint`, with the sender as its owner, and then listed as by
+		/// `set_price`. Nothing is changed if either step fails.
+		///
+		/// - `collection`: The collection of the item to be minted.
+		/// - `item`: The item value of the item to be minted.
+		/// - `price`: The price to list the item for.
+		/// - `whitelisted_buyer`: Restricts the buy operation to a specific account.
+		///
+		/// Emits `Issued` followed by `ItemPriceSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::mint_and_list())]
+		#[transactional]
+		pub fn mint_and_list(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			price: ItemPrice<T, I>,
+			whitelisted_buyer: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
+
+			Self::do_mint(collection, item, origin.clone(), Some(origin.clone()), |details| {
+				ensure!(details.issuer == origin, Error::<T, I>::NoPermission);
+				Ok(())
+			})?;
+			Self::do_set_price(collection, item, origin, Some(price), whitelisted_buyer)
+		}
+
+		/// Move several items of a collection to the same account.
+		///
+		/// Each item is transferred as by `transfer`, so the sender must be permitted to transfer
+		/// every one of them. Unless `force` is set, nothing is changed if any transfer fails.
+		/// With `force`, the items which can't be transferred are skipped.
+		///
+		/// - `collection`: The collection of the items to be transferred.
+		/// - `items`: The items to be transferred.
+		/// - `dest`: The account to receive ownership of the items.
+		/// - `force`: Whether to skip the items which can't be transferred, instead of failing.
+		///
+		/// Emits `Transferred` for each item, followed by `ItemsTransferred` with the items which
+		/// were transferred.
+		///
+		/// Weight: `O(items.len())`
+		#[pallet::weight(T::WeightInfo::transfer_many_to(items.len() as u32))]
+		#[transactional]
+		pub fn transfer_many_to(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			items: BoundedVec<T::ItemId, T::MaxItemsPerTransfer>,
+			dest: AccountIdLookupOf<T>,
+			force: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_transfer_many_to(collection, items, dest, origin, force)
+		}
+
+		/// Post or raise the bond of a collection.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `collection`.
+		///
+		/// The difference to the current bond is reserved from the sender. The bond may be slashed
+		/// by the `ForceOrigin` if the content of the collection is taken down. It moves along with
+		/// the ownership of the collection and is returned when the collection is destroyed.
+		///
+		/// - `collection`: The collection to post the bond for.
+		/// - `amount`: The total bond, which can't be lower than the current one.
+		///
+		/// Emits `CollectionBondSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_collection_bond())]
+		pub fn set_collection_bond(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			amount: DepositBalanceOf<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_collection_bond(collection, amount, origin)
+		}
+
+		/// Slash the bond of a collection, e.g. when its content was taken down.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// The slashed funds are handed to `BondSlashed`. The bond can't be released for
+		/// `BondReleaseCooldown` blocks afterwards.
+		///
+		/// - `collection`: The collection whose bond to slash.
+		/// - `amount`: The amount to slash, at most the whole bond.
+		///
+		/// Emits `CollectionBondSlashed` with the amount actually slashed.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::slash_collection_bond())]
+		pub fn slash_collection_bond(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			amount: DepositBalanceOf<T, I>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_slash_collection_bond(collection, amount)
+		}
+
+		/// Return the bond of a collection to its owner.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `collection`.
+		///
+		/// Fails unless `BondReleaseCooldown` blocks have passed since the bond was last posted or
+		/// slashed.
+		///
+		/// - `collection`: The collection whose bond to release.
+		///
+		/// Emits `CollectionBondReleased`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::release_collection_bond())]
+		pub fn release_collection_bond(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_release_collection_bond(collection, origin)
+		}
+
+		/// Move an item to another account as its approved delegate, using up the approval.
+		///
+		/// Origin must be Signed and the sender must be an approved delegate for the `item` whose
+		/// approval hasn't expired. Unlike `transfer`, being the Owner of the `item` or the Admin
+		/// of the `collection` is not enough.
+		///
+		/// - `collection`: The collection of the item to be transferred.
+		/// - `item`: The item to be transferred.
+		/// - `dest`: The account to receive ownership of the item.
+		///
+		/// Emits `Transferred` followed by `ApprovalConsumed`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_as_delegate())]
+		pub fn transfer_as_delegate(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			dest: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_transfer_as_delegate(collection, item, dest, origin)
+		}
+
+		/// Set the attribute the items of a collection must carry for them to be burned.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// Any burn of an item whose attribute under the `key` of the requirement doesn't equal
+		/// its `value` fails with `BurnConditionNotMet`, e.g. for consumables which may only be
+		/// burned once used up.
+		///
+		/// - `collection`: The collection whose burn requirement to set.
+		/// - `requirement`: The attribute the items must carry, or `None` to allow any burn.
+		///
+		/// Emits `BurnRequirementSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_burn_requirement())]
+		pub fn set_burn_requirement(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			requirement: Option<BurnRequirementFor<T, I>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_set_burn_requirement(collection, requirement, maybe_check_owner)
+		}
+
+		/// Cancel the transfer approvals of the items of a collection in bulk.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// Up to `max_items` items are visited per call, in storage order. Collections with more
+		/// items are cleared over several calls, each passing the `resume_from` of the
+		/// `AllApprovalsCleared` event of the previous one as `start_after`.
+		///
+		/// - `collection`: The collection whose approvals to cancel.
+		/// - `max_items`: The maximum number of items to visit.
+		/// - `start_after`: The item to continue after, or `None` to start from the beginning.
+		///
+		/// Emits `AllApprovalsCleared`.
+		///
+		/// Weight: `O(max_items)`
+		#[pallet::weight(T::WeightInfo::clear_all_collection_approvals(*max_items))]
+		pub fn clear_all_collection_approvals(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			max_items: u32,
+			start_after: Option<T::ItemId>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			Self::do_clear_all_approvals(collection, max_items, start_after, maybe_check_owner)
+		}
+
+		/// Reclaim an item of a collection for its recovery account, e.g. after its owner lost
+		/// their keys.
+		///
+		/// Origin must be Signed and the sender must be the `recovery_account` of the
+		/// collection's config.
+		///
+		/// The item is moved to the sender regardless of freezes, locks, approvals and the
+		/// transfer restrictions of the collection, as by `force_transfer`. Escrowed and wrapped
+		/// items can't be reclaimed.
+		///
+		/// - `collection`: The collection of the item to be reclaimed.
+		/// - `item`: The item to be reclaimed.
+		///
+		/// Emits `Transferred` followed by `ItemRecovered`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::recover_item())]
+		pub fn recover_item(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_recover_item(collection, item, origin)
+		}
+
+		/// Approve the thaw of a frozen item of a collection with an `unlock_threshold` above one.
+		///
+		/// Origin must be Signed and the sender must be the Owner, Issuer, Admin or Freezer of the
+		/// `collection`.
+		///
+		/// Once `unlock_threshold` distinct role holders approved, the item is thawed and the
+		/// approvals are cleared. Freezing the item again clears them as well.
+		///
+		/// - `collection`: The collection of the item to be thawed.
+		/// - `item`: The item to be thawed.
+		///
+		/// Emits `UnlockApprovalAdded`, followed by `Thawed` and `ItemTransferUnlocked` if the
+		/// threshold is met.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::approve_unlock())]
+		pub fn approve_unlock(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_approve_unlock(collection, item, origin)
+		}
 	}
 }
@@ -17,7 +17,26 @@
 
 //! Various pieces of common functionality.
 use super::*;
-use frame_support::traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion};
+use frame_support::{
+	storage::unhashed,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate the pallet storage from any earlier version to the current one, running each of the
+/// migrations below in order.
+pub struct MigrateToLatest<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToLatest<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_to_v1::<T, I, Pallet<T, I>>()
+			.saturating_add(migrate_to_v2::<T, I, Pallet<T, I>>())
+			.saturating_add(migrate_to_v3::<T, I, Pallet<T, I>>())
+			.saturating_add(migrate_to_v4::<T, I, Pallet<T, I>>())
+			.saturating_add(migrate_to_v5::<T, I, Pallet<T, I>>())
+			.saturating_add(migrate_to_v6::<T, I, Pallet<T, I>>())
+	}
+}
 
 /// Migrate the pallet storage to v1.
 pub fn migrate_to_v1<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfoAccess>(
@@ -31,8 +50,16 @@ pub fn migrate_to_v1<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfo
 
 	if on_chain_storage_version < 1 {
 		let mut count = 0;
-		for (collection, detail) in Collection::<T, I>::iter() {
-			CollectionAccount::<T, I>::insert(&detail.owner, &collection, ());
+		// the details of collections only get the layout of `CollectionDetails` with v6.
+		for collection in Collection::<T, I>::iter_keys() {
+			let key = Collection::<T, I>::hashed_key_for(&collection);
+			let owner = unhashed::get::<
+				v5::OldCollectionDetails<T::AccountId, DepositBalanceOf<T, I>>,
+			>(&key)
+			.map(|detail| detail.owner);
+			if let Some(owner) = owner {
+				CollectionAccount::<T, I>::insert(&owner, &collection, ());
+			}
 			count += 1;
 		}
 		StorageVersion::new(1).put::<P>();
@@ -52,3 +79,272 @@ pub fn migrate_to_v1<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfo
 		T::DbWeight::get().reads(1)
 	}
 }
+
+mod v1 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldItemDetails<AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub approved: Option<AccountId>,
+		pub is_frozen: bool,
+		pub deposit: DepositBalance,
+	}
+}
+
+/// Migrate the pallet storage to v2, replacing the single approved account of each item by a
+/// set of approvals without a deadline.
+pub fn migrate_to_v2<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfoAccess>(
+) -> frame_support::weights::Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	log::info!(
+		target: "runtime::uniques",
+		"Running migration storage v2 for uniques with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version == 1 {
+		let mut count = 0;
+		Item::<T, I>::translate::<v1::OldItemDetails<T::AccountId, DepositBalanceOf<T, I>>, _>(
+			|_, _, old| {
+				count += 1;
+				let mut approvals = ApprovalsOf::<T, I>::new();
+				if let Some(approved) = old.approved {
+					// only possible if `MaxApprovalsPerItem` is zero, then the approval is dropped.
+					let _ = approvals.try_insert(approved, None);
+				}
+				Some(ItemDetails {
+					owner: old.owner,
+					approvals,
+					is_frozen: old.is_frozen,
+					deposit: old.deposit,
+				})
+			},
+		);
+		StorageVersion::new(2).put::<P>();
+		log::info!(
+			target: "runtime::uniques",
+			"Running migration storage v2 for uniques with storage version {:?} was complete",
+			on_chain_storage_version,
+		);
+		// calculate and return migration weights
+		T::DbWeight::get().reads_writes(count as u64 + 1, count as u64 + 1)
+	} else {
+		log::warn!(
+			target: "runtime::uniques",
+			"Attempted to apply migration to v2 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+		T::DbWeight::get().reads(1)
+	}
+}
+
+/// Migrate the pallet storage to v3, counting the collections owned by each account.
+pub fn migrate_to_v3<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfoAccess>(
+) -> frame_support::weights::Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	log::info!(
+		target: "runtime::uniques",
+		"Running migration storage v3 for uniques with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version == 2 {
+		let mut count = 0;
+		for (owner, _) in CollectionAccount::<T, I>::iter_keys() {
+			OwnedCollectionsCountOf::<T, I>::mutate(&owner, |owned| owned.saturating_inc());
+			count += 1;
+		}
+		StorageVersion::new(3).put::<P>();
+		log::info!(
+			target: "runtime::uniques",
+			"Running migration storage v3 for uniques with storage version {:?} was complete",
+			on_chain_storage_version,
+		);
+		// calculate and return migration weights
+		T::DbWeight::get().reads_writes(count as u64 * 2 + 1, count as u64 + 1)
+	} else {
+		log::warn!(
+			target: "runtime::uniques",
+			"Attempted to apply migration to v3 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+		T::DbWeight::get().reads(1)
+	}
+}
+
+mod v3 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub struct OldItemMetadata<DepositBalance, StringLimit: Get<u32>> {
+		pub deposit: DepositBalance,
+		pub data: BoundedVec<u8, StringLimit>,
+		pub is_frozen: bool,
+	}
+}
+
+/// Migrate the pallet storage to v4, giving the metadata of each item an empty storage proof.
+pub fn migrate_to_v4<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfoAccess>(
+) -> frame_support::weights::Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	log::info!(
+		target: "runtime::uniques",
+		"Running migration storage v4 for uniques with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version == 3 {
+		let mut count = 0;
+		ItemMetadataOf::<T, I>::translate::<
+			v3::OldItemMetadata<DepositBalanceOf<T, I>, T::StringLimit>,
+			_,
+		>(|_, _, old| {
+			count += 1;
+			Some(ItemMetadata {
+				deposit: old.deposit,
+				data: old.data,
+				is_frozen: old.is_frozen,
+				storage_proof: None,
+			})
+		});
+		StorageVersion::new(4).put::<P>();
+		log::info!(
+			target: "runtime::uniques",
+			"Running migration storage v4 for uniques with storage version {:?} was complete",
+			on_chain_storage_version,
+		);
+		// calculate and return migration weights
+		T::DbWeight::get().reads_writes(count as u64 + 1, count as u64 + 1)
+	} else {
+		log::warn!(
+			target: "runtime::uniques",
+			"Attempted to apply migration to v4 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+		T::DbWeight::get().reads(1)
+	}
+}
+
+/// Migrate the pallet storage to v5, counting the distinct owners of each collection.
+pub fn migrate_to_v5<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfoAccess>(
+) -> frame_support::weights::Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	log::info!(
+		target: "runtime::uniques",
+		"Running migration storage v5 for uniques with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version == 4 {
+		let mut reads = 0;
+		let mut writes = 0;
+		// the keys of each owner are next to each other, grouped by collection.
+		let mut last = None;
+		for (owner, collection, _) in Account::<T, I>::iter_keys() {
+			reads += 1;
+			let key = (owner, collection);
+			if last.as_ref() != Some(&key) && Pallet::<T, I>::counts_as_owner(&key.0) {
+				DistinctOwnersCountOf::<T, I>::mutate(&key.1, |count| count.saturating_inc());
+				writes += 1;
+				last = Some(key);
+			}
+		}
+		StorageVersion::new(5).put::<P>();
+		log::info!(
+			target: "runtime::uniques",
+			"Running migration storage v5 for uniques with storage version {:?} was complete",
+			on_chain_storage_version,
+		);
+		// calculate and return migration weights
+		T::DbWeight::get().reads_writes(reads + writes + 1, writes + 1)
+	} else {
+		log::warn!(
+			target: "runtime::uniques",
+			"Attempted to apply migration to v5 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+		T::DbWeight::get().reads(1)
+	}
+}
+
+mod v5 {
+	use super::*;
+
+	/// The details of a collection up to v5.
+	#[derive(Decode)]
+	pub struct OldCollectionDetails<AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub issuer: AccountId,
+		pub admin: AccountId,
+		pub freezer: AccountId,
+		pub total_deposit: DepositBalance,
+		pub free_holding: bool,
+		pub items: u32,
+		pub item_metadatas: u32,
+		pub attributes: u32,
+		pub is_frozen: bool,
+	}
+}
+
+/// Migrate the pallet storage to v6, disclosing the recovery account in the details of each
+/// collection, and recording the deposit each collection was created with.
+pub fn migrate_to_v6<T: Config<I>, I: 'static, P: GetStorageVersion + PalletInfoAccess>(
+) -> frame_support::weights::Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	log::info!(
+		target: "runtime::uniques",
+		"Running migration storage v6 for uniques with storage version {:?}",
+		on_chain_storage_version,
+	);
+
+	if on_chain_storage_version == 5 {
+		let mut reads = 0;
+		let mut writes = 0;
+		Collection::<T, I>::translate::<
+			v5::OldCollectionDetails<T::AccountId, DepositBalanceOf<T, I>>,
+			_,
+		>(|collection, old| {
+			// the config, and the deposits of the metadata, items and attributes.
+			reads += 3 + old.items as u64 + old.item_metadatas as u64 + old.attributes as u64;
+			writes += 2;
+			// whatever isn't accounted for by the metadata, items and attributes of a collection
+			// was reserved for its creation.
+			let recorded = Pallet::<T, I>::recorded_deposits(collection);
+			CollectionCreationDepositOf::<T, I>::insert(
+				&collection,
+				old.total_deposit.saturating_sub(recorded),
+			);
+			Some(CollectionDetails {
+				owner: old.owner,
+				issuer: old.issuer,
+				admin: old.admin,
+				freezer: old.freezer,
+				total_deposit: old.total_deposit,
+				free_holding: old.free_holding,
+				items: old.items,
+				item_metadatas: old.item_metadatas,
+				attributes: old.attributes,
+				is_frozen: old.is_frozen,
+				has_recovery_account: CollectionConfigOf::<T, I>::get(&collection)
+					.recovery_account
+					.is_some(),
+			})
+		});
+		StorageVersion::new(6).put::<P>();
+		log::info!(
+			target: "runtime::uniques",
+			"Running migration storage v6 for uniques with storage version {:?} was complete",
+			on_chain_storage_version,
+		);
+		// calculate and return migration weights
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	} else {
+		log::warn!(
+			target: "runtime::uniques",
+			"Attempted to apply migration to v6 but failed because storage version is {:?}",
+			on_chain_storage_version,
+		);
+		T::DbWeight::get().reads(1)
+	}
+}
@@ -21,13 +21,15 @@ use super::*;
 use crate as pallet_uniques;
 
 use frame_support::{
-	construct_runtime,
+	construct_runtime, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError, DispatchResult,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -84,22 +86,57 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+parameter_types! {
+	pub const UniquesPalletId: PalletId = PalletId(*b"py/uniqs");
+	pub static SanctionedAccount: Option<u64> = None;
+	pub static FreeCollections: u32 = 10;
+}
+
+/// Rejects all transfers to or from the `SanctionedAccount`.
+pub struct SanctionsPolicy;
+impl TransferPolicy<u64, u32, u32> for SanctionsPolicy {
+	fn can_transfer(_: &u32, _: &u32, from: &u64, to: &u64) -> DispatchResult {
+		match SanctionedAccount::get() {
+			Some(sanctioned) if sanctioned == *from || sanctioned == *to =>
+				Err(DispatchError::Other("sanctioned")),
+			_ => Ok(()),
+		}
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type CollectionId = u32;
 	type ItemId = u32;
+	type CollectionIdConverter = ();
 	type Currency = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type Locker = ();
+	type TransferPolicy = SanctionsPolicy;
 	type CollectionDeposit = ConstU64<2>;
+	type CollectionDepositTiers = EscalatingDeposit<FreeCollections>;
 	type ItemDeposit = ConstU64<1>;
 	type MetadataDepositBase = ConstU64<1>;
 	type AttributeDepositBase = ConstU64<1>;
 	type DepositPerByte = ConstU64<1>;
 	type StringLimit = ConstU32<50>;
+	type ItemNameLimit = ConstU32<16>;
 	type KeyLimit = ConstU32<50>;
 	type ValueLimit = ConstU32<50>;
+	type PalletId = UniquesPalletId;
+	type EscrowTimeout = ConstU64<10>;
+	type MintStakeCooldown = ConstU64<5>;
+	type MaxEscrowsPerBlock = ConstU32<2>;
+	type MaxRecurringLocks = ConstU32<2>;
+	type MaxApprovalsPerItem = ConstU32<2>;
+	type MaxRoleAssignments = ConstU32<3>;
+	type MaxItemsPerQuery = ConstU32<3>;
+	type MaxMarketplaces = ConstU32<2>;
+	type MaxItemsPerTransfer = ConstU32<3>;
+	type BondReleaseCooldown = ConstU64<10>;
+	type BondSlashed = ();
+	type FeeCollector = ();
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
@@ -0,0 +1,124 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functionality for freezing collections during recurring windows.
+
+use super::*;
+use frame_support::{ensure, traits::Get, weights::Weight};
+use sp_runtime::{traits::Zero, DispatchResult};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_lock_collection_recurring(
+		collection: T::CollectionId,
+		duration: T::BlockNumber,
+		period: T::BlockNumber,
+		freezer: T::AccountId,
+	) -> DispatchResult {
+		ensure!(!duration.is_zero() && duration < period, Error::<T, I>::InvalidLockPeriod);
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(details.freezer == freezer, Error::<T, I>::NoPermission);
+
+		if let Some(lock) = RecurringLocksOf::<T, I>::get(&collection) {
+			Self::remove_lock_transition(lock.next, collection);
+		} else {
+			ensure!(
+				RecurringLocksOf::<T, I>::count() < T::MaxRecurringLocks::get(),
+				Error::<T, I>::TooManyRecurringLocks
+			);
+		}
+
+		let next = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+		RecurringLockTransitions::<T, I>::try_append(next, collection)
+			.map_err(|_| Error::<T, I>::TooManyRecurringLocks)?;
+		RecurringLocksOf::<T, I>::insert(
+			&collection,
+			RecurringLock { duration, period, next, frozen: true },
+		);
+
+		Self::set_collection_frozen(collection, true);
+		Self::deposit_event(Event::RecurringLockSet { collection, duration, period });
+		Ok(())
+	}
+
+	pub fn do_cancel_recurring_lock(
+		collection: T::CollectionId,
+		freezer: T::AccountId,
+	) -> DispatchResult {
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(details.freezer == freezer, Error::<T, I>::NoPermission);
+
+		let lock =
+			RecurringLocksOf::<T, I>::take(&collection).ok_or(Error::<T, I>::NoRecurringLock)?;
+		Self::remove_lock_transition(lock.next, collection);
+
+		Self::deposit_event(Event::RecurringLockCancelled { collection });
+		Ok(())
+	}
+
+	/// Freeze or thaw all collections whose recurring lock changes at block `now`.
+	pub(crate) fn cycle_recurring_locks(now: T::BlockNumber) -> Weight {
+		let transitioning = RecurringLockTransitions::<T, I>::take(now);
+		let count = transitioning.len() as u32;
+		for collection in transitioning {
+			if let Some(mut lock) = RecurringLocksOf::<T, I>::get(&collection) {
+				lock.frozen = !lock.frozen;
+				let wait = if lock.frozen {
+					lock.duration
+				} else {
+					lock.period.saturating_sub(lock.duration)
+				};
+				lock.next = now.saturating_add(wait);
+				// can't fail, as there are never more schedules than fit in a single block.
+				if RecurringLockTransitions::<T, I>::try_append(lock.next, collection).is_ok() {
+					RecurringLocksOf::<T, I>::insert(&collection, &lock);
+				} else {
+					RecurringLocksOf::<T, I>::remove(&collection);
+				}
+				Self::set_collection_frozen(collection, lock.frozen);
+			}
+		}
+		T::WeightInfo::cycle_recurring_locks(count)
+	}
+
+	/// Drop the recurring lock of `collection`, if it has one.
+	pub(crate) fn remove_recurring_lock(collection: T::CollectionId) {
+		if let Some(lock) = RecurringLocksOf::<T, I>::take(&collection) {
+			Self::remove_lock_transition(lock.next, collection);
+		}
+	}
+
+	fn set_collection_frozen(collection: T::CollectionId, frozen: bool) {
+		Collection::<T, I>::mutate(&collection, |maybe_details| {
+			if let Some(details) = maybe_details {
+				details.is_frozen = frozen;
+			}
+		});
+		if frozen {
+			Self::deposit_event(Event::CollectionFrozen { collection });
+		} else {
+			Self::deposit_event(Event::CollectionThawed { collection });
+		}
+	}
+
+	fn remove_lock_transition(at: T::BlockNumber, collection: T::CollectionId) {
+		RecurringLockTransitions::<T, I>::mutate(at, |transitioning| {
+			transitioning.retain(|entry| entry != &collection)
+		});
+	}
+}
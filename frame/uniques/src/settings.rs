@@ -0,0 +1,508 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functionality for the per-collection configuration and the checks it drives.
+
+use super::*;
+use frame_support::{ensure, traits::tokens::nonfungibles::Inspect};
+use sp_runtime::DispatchResult;
+
+/// Settings which only `ForceOrigin` may switch on or off.
+const GOVERNANCE_SETTINGS: [CollectionSetting; 1] = [CollectionSetting::FreeAttributes];
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	pub fn do_set_collection_config(
+		collection: T::CollectionId,
+		config: CollectionConfigFor<T, I>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let mut details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(membership) = &config.required_membership {
+			ensure!(Collection::<T, I>::contains_key(membership), Error::<T, I>::UnknownCollection);
+		}
+		ensure!(
+			config.unlock_threshold as u32 <= MAX_UNLOCK_APPROVERS,
+			Error::<T, I>::InvalidUnlockThreshold
+		);
+		let old_config = CollectionConfigOf::<T, I>::get(&collection);
+		// ids minted before `SequentialIds` could collide with the ones it allocates.
+		if config.settings.is_enabled(CollectionSetting::SequentialIds) &&
+			!old_config.settings.is_enabled(CollectionSetting::SequentialIds)
+		{
+			ensure!(details.items == 0, Error::<T, I>::CollectionNotEmpty);
+		}
+		// names given before `UniqueItemNames` are not indexed, nor are ones given while it's on
+		// dropped from the index when it's switched off.
+		if config.settings.is_enabled(CollectionSetting::UniqueItemNames) !=
+			old_config.settings.is_enabled(CollectionSetting::UniqueItemNames)
+		{
+			ensure!(
+				ItemNameOf::<T, I>::iter_prefix(&collection).next().is_none(),
+				Error::<T, I>::ItemsNamed
+			);
+		}
+		// only `do_reveal_collection` reveals a collection, which then stays revealed.
+		ensure!(
+			old_config.settings.is_enabled(CollectionSetting::Revealed) ==
+				config.settings.is_enabled(CollectionSetting::Revealed),
+			Error::<T, I>::NoPermission
+		);
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+			ensure!(
+				!old_config.settings.is_enabled(CollectionSetting::SettingsLocked),
+				Error::<T, I>::SettingsLocked
+			);
+			ensure!(
+				!old_config.settings.is_enabled(CollectionSetting::ContentFrozen) ||
+					config.settings.is_enabled(CollectionSetting::ContentFrozen),
+				Error::<T, I>::Frozen
+			);
+			for setting in GOVERNANCE_SETTINGS {
+				ensure!(
+					old_config.settings.is_enabled(setting) == config.settings.is_enabled(setting),
+					Error::<T, I>::NoPermission
+				);
+			}
+			// holders of items must be able to rely on the recovery account they got them with.
+			ensure!(
+				config.recovery_account.is_none() ||
+					config.recovery_account == old_config.recovery_account ||
+					details.items == 0,
+				Error::<T, I>::CollectionNotEmpty
+			);
+			// nor on the number of approvals needed to thaw them.
+			ensure!(
+				config.unlock_threshold == old_config.unlock_threshold || details.items == 0,
+				Error::<T, I>::CollectionNotEmpty
+			);
+		}
+
+		let has_recovery_account = config.recovery_account.is_some();
+		if details.has_recovery_account != has_recovery_account {
+			details.has_recovery_account = has_recovery_account;
+			Collection::<T, I>::insert(&collection, &details);
+		}
+		CollectionConfigOf::<T, I>::insert(&collection, &config);
+		Self::deposit_event(Event::CollectionConfigChanged { collection, config });
+		Ok(())
+	}
+
+	pub fn do_freeze_collection_content(
+		collection: T::CollectionId,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		CollectionConfigOf::<T, I>::mutate(&collection, |config| {
+			config.settings.0.insert(CollectionSetting::ContentFrozen)
+		});
+		Self::deposit_event(Event::CollectionContentFrozen { collection });
+		Ok(())
+	}
+
+	pub fn do_set_marketplace_policy(
+		collection: T::CollectionId,
+		policy: MarketplacePolicyFor<T, I>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+			ensure!(!Self::are_settings_locked(collection), Error::<T, I>::SettingsLocked);
+		}
+
+		MarketplacePolicyOf::<T, I>::insert(&collection, &policy);
+		Self::deposit_event(Event::MarketplacePolicySet { collection, policy });
+		Ok(())
+	}
+
+	/// Set the attribute the items of `collection` must carry for them to be burned.
+	pub fn do_set_burn_requirement(
+		collection: T::CollectionId,
+		requirement: Option<BurnRequirementFor<T, I>>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(
+				Self::is_collection_owner(collection, check_owner),
+				Error::<T, I>::NoPermission
+			);
+			ensure!(!Self::are_settings_locked(collection), Error::<T, I>::SettingsLocked);
+		}
+
+		BurnRequirementOf::<T, I>::set(&collection, requirement.clone());
+		Self::deposit_event(Event::BurnRequirementSet { collection, requirement });
+		Ok(())
+	}
+
+	/// Ensure that `item` carries the attribute `collection` requires for its items to be burned,
+	/// if any.
+	pub(crate) fn ensure_burn_condition_met(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> DispatchResult {
+		if let Some(requirement) = BurnRequirementOf::<T, I>::get(collection) {
+			let value =
+				<Self as Inspect<T::AccountId>>::attribute(collection, item, &requirement.key);
+			ensure!(
+				value.as_deref() == Some(&requirement.value[..]),
+				Error::<T, I>::BurnConditionNotMet
+			);
+		}
+		Ok(())
+	}
+
+	/// Returns `true` if `setting` is switched on for `collection`.
+	pub fn is_collection_setting_enabled(
+		collection: &T::CollectionId,
+		setting: CollectionSetting,
+	) -> bool {
+		CollectionConfigOf::<T, I>::get(collection).settings.is_enabled(setting)
+	}
+
+	/// Returns `true` if the owner of `collection` may no longer change its configuration.
+	pub fn are_settings_locked(collection: T::CollectionId) -> bool {
+		Self::is_collection_setting_enabled(&collection, CollectionSetting::SettingsLocked)
+	}
+
+	/// Ensure that `config` allows an item currently owned by `from` to be transferred to `dest`.
+	///
+	/// If the collection requires a membership, `dest` must hold an item of the membership
+	/// collection.
+	pub(crate) fn ensure_transfer_allowed(
+		config: &CollectionConfigFor<T, I>,
+		from: &T::AccountId,
+		dest: &T::AccountId,
+	) -> DispatchResult {
+		if config.settings.is_enabled(CollectionSetting::ForbidSelfTransfer) {
+			ensure!(from != dest, Error::<T, I>::CannotTransferToSelf);
+		}
+		if let Some(membership) = &config.required_membership {
+			ensure!(
+				Account::<T, I>::iter_key_prefix((dest, membership)).next().is_some(),
+				Error::<T, I>::DestinationNotMember
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure that `config` allows `item` to be minted by `maybe_minter`.
+	///
+	/// Ids up to the reserved ceiling are left to the Issuer of the collection, and the id must
+	/// have the parity required by the collection.
+	pub(crate) fn ensure_item_id_allowed(
+		config: &CollectionConfigFor<T, I>,
+		collection_details: &CollectionDetailsFor<T, I>,
+		item: &T::ItemId,
+		maybe_minter: &Option<T::AccountId>,
+	) -> DispatchResult {
+		if let Some(ceiling) = &config.reserved_item_id_ceiling {
+			ensure!(
+				item > ceiling || maybe_minter.as_ref() == Some(&collection_details.issuer),
+				Error::<T, I>::ReservedItemId
+			);
+		}
+		ensure!(config.id_parity.allows(item), Error::<T, I>::WrongIdParity);
+		Ok(())
+	}
+
+	/// Ensure that `config` allows the item to be transferred for the first time, if it is.
+	pub(crate) fn ensure_first_transfer_approved(
+		config: &CollectionConfigFor<T, I>,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> DispatchResult {
+		if config.settings.is_enabled(CollectionSetting::RequireFirstTransferApproval) &&
+			TransferCountOf::<T, I>::get(collection, item) == 0
+		{
+			ensure!(
+				FirstTransferApprovedOf::<T, I>::contains_key(collection, item),
+				Error::<T, I>::FirstTransferNotApproved
+			);
+		}
+		Ok(())
+	}
+
+	pub fn do_approve_first_transfer(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		admin: T::AccountId,
+	) -> DispatchResult {
+		let collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		ensure!(collection_details.admin == admin, Error::<T, I>::NoPermission);
+		ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+
+		FirstTransferApprovedOf::<T, I>::insert(&collection, &item, ());
+		Self::deposit_event(Event::FirstTransferApproved { collection, item });
+		Ok(())
+	}
+
+	/// Freeze the item which was just transferred, if the collection has `FreezeAfterTransfer`.
+	pub(crate) fn freeze_after_transfer(collection: T::CollectionId, item: T::ItemId) {
+		if !Self::is_collection_setting_enabled(&collection, CollectionSetting::FreezeAfterTransfer)
+		{
+			return
+		}
+		// the item is gone if it was transferred to the sink of the collection.
+		let exists = Item::<T, I>::mutate(&collection, &item, |maybe_details| {
+			maybe_details.as_mut().map(|details| details.is_frozen = true).is_some()
+		});
+		if exists {
+			Self::deposit_event(Event::ItemTransferLocked { collection, item });
+		}
+	}
+
+	/// Ensure that the burn policy of `config` allows `burner` to burn the item with `details`.
+	pub(crate) fn ensure_burn_allowed(
+		config: &CollectionConfigFor<T, I>,
+		collection_details: &CollectionDetailsFor<T, I>,
+		details: &ItemDetailsFor<T, I>,
+		burner: &T::AccountId,
+	) -> DispatchResult {
+		let is_owner = &collection_details.admin == burner || &details.owner == burner;
+		let is_permitted = match config.burn_policy {
+			BurnPolicy::OwnerOnly => is_owner,
+			BurnPolicy::ApprovedOrOwner =>
+				is_owner || Self::check_approval(details, burner).is_ok(),
+			BurnPolicy::Anyone => true,
+		};
+		ensure!(is_permitted, Error::<T, I>::NoPermission);
+		Ok(())
+	}
+
+	/// Ensure that the metadata, attributes and names of `collection` may still be changed.
+	///
+	/// `ForceOrigin`, signalled by `maybe_check_owner` being `None`, may always change them.
+	pub(crate) fn ensure_content_writable(
+		collection: &T::CollectionId,
+		maybe_check_owner: &Option<T::AccountId>,
+	) -> DispatchResult {
+		if maybe_check_owner.is_some() {
+			ensure!(
+				!Self::is_collection_setting_enabled(collection, CollectionSetting::ContentFrozen),
+				Error::<T, I>::Frozen
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure that `who` may write the metadata of `collection`, whose details are `details`.
+	///
+	/// This is the Issuer if the collection has `IssuerManagesMetadata` switched on, and the
+	/// owner otherwise.
+	pub(crate) fn ensure_metadata_writer(
+		collection: &T::CollectionId,
+		details: &CollectionDetailsFor<T, I>,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let writer = if Self::is_collection_setting_enabled(
+			collection,
+			CollectionSetting::IssuerManagesMetadata,
+		) {
+			&details.issuer
+		} else {
+			&details.owner
+		};
+		ensure!(writer == who, Error::<T, I>::NoPermission);
+		Ok(())
+	}
+
+	/// Ensure that the metadata of `item` may be updated, given the `metadata_update_cooldown` of
+	/// `collection`.
+	///
+	/// `ForceOrigin`, signalled by `maybe_check_owner` being `None`, may always update it.
+	pub(crate) fn ensure_metadata_update_allowed(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		maybe_check_owner: &Option<T::AccountId>,
+	) -> DispatchResult {
+		let cooldown = match CollectionConfigOf::<T, I>::get(collection).metadata_update_cooldown {
+			Some(cooldown) if maybe_check_owner.is_some() => cooldown,
+			_ => return Ok(()),
+		};
+		if let Some(last) = LastMetadataUpdateOf::<T, I>::get(collection, item) {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= last.saturating_add(cooldown),
+				Error::<T, I>::MetadataUpdateTooSoon
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure that the metadata of `item` may still be updated, given the
+	/// `metadata_mutable_until_age` of `collection`.
+	///
+	/// Items whose mint block isn't known count as too old. `ForceOrigin`, signalled by
+	/// `maybe_check_owner` being `None`, may always update it.
+	pub(crate) fn ensure_metadata_window_open(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+		maybe_check_owner: &Option<T::AccountId>,
+	) -> DispatchResult {
+		let window = match CollectionConfigOf::<T, I>::get(collection).metadata_mutable_until_age {
+			Some(window) if maybe_check_owner.is_some() => window,
+			_ => return Ok(()),
+		};
+		let minted_at = ItemMintedAt::<T, I>::get(collection, item)
+			.ok_or(Error::<T, I>::MetadataWindowClosed)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= minted_at.saturating_add(window),
+			Error::<T, I>::MetadataWindowClosed
+		);
+		Ok(())
+	}
+
+	/// Ensure that `dest` may receive an item of `collection` from `maybe_source`, or a new one if
+	/// that is `None`, given its `max_distinct_owners`.
+	///
+	/// A source giving away its last item makes room for `dest`.
+	pub(crate) fn ensure_owner_count_allowed(
+		collection: &T::CollectionId,
+		config: &CollectionConfigFor<T, I>,
+		maybe_source: Option<&T::AccountId>,
+		dest: &T::AccountId,
+	) -> DispatchResult {
+		let max = match config.max_distinct_owners {
+			Some(max) => max,
+			None => return Ok(()),
+		};
+		if Self::owns_any_in_collection(dest, *collection) {
+			return Ok(())
+		}
+		let source_leaves = maybe_source.map_or(false, |source| {
+			Account::<T, I>::iter_key_prefix((source, collection)).nth(1).is_none()
+		});
+		ensure!(
+			source_leaves || DistinctOwnersCountOf::<T, I>::get(collection) < max,
+			Error::<T, I>::TooManyOwners
+		);
+		Ok(())
+	}
+
+	/// Ensure that `item` was held for at least the `holding_period` of its collection since it
+	/// was last minted or transferred.
+	pub(crate) fn ensure_holding_period_passed(
+		config: &CollectionConfigFor<T, I>,
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> DispatchResult {
+		let period = match config.holding_period {
+			Some(period) => period,
+			None => return Ok(()),
+		};
+		let acquired_at = LastTransferOf::<T, I>::get(collection, item)
+			.or_else(|| ItemMintedAt::<T, I>::get(collection, item));
+		if let Some(acquired_at) = acquired_at {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= acquired_at.saturating_add(period),
+				Error::<T, I>::HoldingPeriodActive
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure that `minter` may mint, given the `mint_cooldown_per_account` of `collection`, and
+	/// note that it is minting now.
+	pub(crate) fn ensure_mint_cooldown_passed(
+		collection: &T::CollectionId,
+		config: &CollectionConfigFor<T, I>,
+		minter: &T::AccountId,
+	) -> DispatchResult {
+		let cooldown = match config.mint_cooldown_per_account {
+			Some(cooldown) => cooldown,
+			None => return Ok(()),
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(last) = LastMintByAccountOf::<T, I>::get(collection, minter) {
+			ensure!(now >= last.saturating_add(cooldown), Error::<T, I>::MintCooldown);
+		}
+		LastMintByAccountOf::<T, I>::insert(collection, minter, now);
+		Ok(())
+	}
+
+	/// Ensure that the marketplace policy of `collection` allows `delegate` to be approved.
+	pub(crate) fn ensure_marketplace_allowed(
+		collection: &T::CollectionId,
+		delegate: &T::AccountId,
+	) -> DispatchResult {
+		let allowed = match MarketplacePolicyOf::<T, I>::get(collection) {
+			MarketplacePolicy::Any => true,
+			MarketplacePolicy::Allowlist(allowed) => allowed.contains(delegate),
+			MarketplacePolicy::Denylist(denied) => !denied.contains(delegate),
+		};
+		ensure!(allowed, Error::<T, I>::MarketplaceNotAllowed);
+		Ok(())
+	}
+
+	/// Ensure that one more attribute may be set in `collection`, given its `collection_details`.
+	pub(crate) fn ensure_attribute_allowed(
+		collection: &T::CollectionId,
+		collection_details: &CollectionDetailsFor<T, I>,
+	) -> DispatchResult {
+		if let Some(max) = CollectionConfigOf::<T, I>::get(collection).max_total_attributes {
+			ensure!(
+				collection_details.attributes < max,
+				Error::<T, I>::CollectionAttributeLimitReached
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure that `data` may be set as metadata of the item or collection in `collection`.
+	pub(crate) fn ensure_metadata_allowed(
+		collection: &T::CollectionId,
+		data: &[u8],
+	) -> DispatchResult {
+		if Self::is_collection_setting_enabled(collection, CollectionSetting::OffchainMetadataOnly)
+		{
+			ensure!(is_uri(data), Error::<T, I>::InlineMetadataForbidden);
+		}
+		Ok(())
+	}
+}
+
+/// A lightweight check that `data` has the shape `scheme://rest`.
+///
+/// The scheme must follow RFC 3986, while `rest` only has to be non-empty and free of whitespace.
+fn is_uri(data: &[u8]) -> bool {
+	match data.windows(3).position(|w| w == b"://") {
+		Some(pos) if pos > 0 => {
+			let (scheme, rest) = (&data[..pos], &data[pos + 3..]);
+			scheme[0].is_ascii_alphabetic() &&
+				scheme.iter().all(|c| c.is_ascii_alphanumeric() || b"+-.".contains(c)) &&
+				!rest.is_empty() &&
+				rest.iter().all(|c| c.is_ascii_graphic())
+		},
+		_ => false,
+	}
+}
@@ -0,0 +1,103 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for tests to capture the state of a collection and put it back later.
+
+use super::*;
+use frame_support::{CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+
+/// The attribute of a collection, keyed by the item it belongs to, if any, and its key.
+type AttributeEntry<T, I> = (
+	(Option<<T as Config<I>>::ItemId>, BoundedVec<u8, <T as Config<I>>::KeyLimit>),
+	(BoundedVec<u8, <T as Config<I>>::ValueLimit>, DepositBalanceOf<T, I>),
+);
+
+/// The state of a collection, as captured by [`Pallet::snapshot_collection`].
+#[derive(CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound)]
+pub struct CollectionSnapshot<T: Config<I>, I: 'static = ()> {
+	collection: T::CollectionId,
+	details: CollectionDetailsFor<T, I>,
+	config: CollectionConfigFor<T, I>,
+	metadata: Option<CollectionMetadata<DepositBalanceOf<T, I>, T::StringLimit>>,
+	items: Vec<(
+		T::ItemId,
+		ItemDetailsFor<T, I>,
+		Option<ItemMetadata<DepositBalanceOf<T, I>, T::StringLimit>>,
+	)>,
+	attributes: Vec<AttributeEntry<T, I>>,
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Capture the details, roles, config, items, metadata and attributes of `collection`.
+	///
+	/// Returns `None` if the collection doesn't exist.
+	pub fn snapshot_collection(collection: T::CollectionId) -> Option<CollectionSnapshot<T, I>> {
+		let details = Collection::<T, I>::get(&collection)?;
+		let items = Item::<T, I>::iter_prefix(&collection)
+			.map(|(item, details)| (item, details, ItemMetadataOf::<T, I>::get(&collection, &item)))
+			.collect();
+		Some(CollectionSnapshot {
+			collection,
+			details,
+			config: CollectionConfigOf::<T, I>::get(&collection),
+			metadata: CollectionMetadataOf::<T, I>::get(&collection),
+			items,
+			attributes: Attribute::<T, I>::iter_prefix((&collection,)).collect(),
+		})
+	}
+
+	/// Put a collection back into the state captured by `snapshot`.
+	///
+	/// Any items, metadata and attributes the collection gained since are dropped. Deposits are
+	/// restored as recorded, without reserving or unreserving any funds.
+	pub fn restore_collection(snapshot: CollectionSnapshot<T, I>) {
+		let CollectionSnapshot { collection, details, config, metadata, items, attributes } =
+			snapshot;
+
+		for (item, item_details) in Item::<T, I>::drain_prefix(&collection) {
+			Self::remove_account_item(&item_details.owner, collection, item);
+		}
+		#[allow(deprecated)]
+		ItemMetadataOf::<T, I>::remove_prefix(&collection, None);
+		#[allow(deprecated)]
+		Attribute::<T, I>::remove_prefix((&collection,), None);
+
+		match Collection::<T, I>::get(&collection) {
+			Some(current) if current.owner != details.owner =>
+				Self::move_collection_account(collection, &current.owner, &details.owner),
+			Some(_) => {},
+			None => {
+				CollectionAccount::<T, I>::insert(&details.owner, &collection, ());
+				OwnedCollectionsCountOf::<T, I>::mutate(&details.owner, |count| {
+					count.saturating_inc()
+				});
+			},
+		}
+		Collection::<T, I>::insert(&collection, details);
+		CollectionConfigOf::<T, I>::insert(&collection, config);
+		CollectionMetadataOf::<T, I>::set(&collection, metadata);
+
+		for (item, item_details, item_metadata) in items {
+			Self::insert_account_item(&item_details.owner, collection, item);
+			Item::<T, I>::insert(&collection, &item, item_details);
+			ItemMetadataOf::<T, I>::set(&collection, &item, item_metadata);
+		}
+		for ((maybe_item, key), value) in attributes {
+			Attribute::<T, I>::insert((&collection, maybe_item, key), value);
+		}
+	}
+}
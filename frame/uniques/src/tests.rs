@@ -18,8 +18,19 @@
 //! Tests for Uniques pallet.
 
 use crate::{mock::*, Event, *};
-use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable, traits::Currency};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::Dispatchable,
+	storage::unhashed,
+	traits::{
+		tokens::nonfungibles::{Inspect, Mutate},
+		Currency, GetStorageVersion, Hooks, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+	},
+	BoundedBTreeSet,
+};
 use pallet_balances::Error as BalancesError;
+use sp_core::H256;
+use sp_runtime::{traits::Hash, DispatchError};
 use sp_std::prelude::*;
 
 fn items() -> Vec<(u64, u32, u32)> {
@@ -128,10 +139,24 @@ fn lifecycle_should_work() {
 		assert_eq!(Collection::<Test>::get(0).unwrap().items, 2);
 		assert_eq!(Collection::<Test>::get(0).unwrap().item_metadatas, 0);
 
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![42, 42], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![42, 42],
+			false,
+			None
+		));
 		assert_eq!(Balances::reserved_balance(&1), 10);
 		assert!(ItemMetadataOf::<Test>::contains_key(0, 42));
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 69, bvec![69, 69], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			69,
+			bvec![69, 69],
+			false,
+			None
+		));
 		assert_eq!(Balances::reserved_balance(&1), 13);
 		assert!(ItemMetadataOf::<Test>::contains_key(0, 69));
 
@@ -188,7 +213,7 @@ fn transfer_should_work() {
 			Error::<Test>::NoPermission
 		);
 
-		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(3), 0, 42, 2));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(3), 0, 42, 2, None));
 		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 4));
 	});
 }
@@ -276,7 +301,14 @@ fn transfer_owner_should_work() {
 			false
 		));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 1));
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(2), 0, 42, bvec![0u8; 20], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(2),
+			0,
+			42,
+			bvec![0u8; 20],
+			false,
+			None
+		));
 		assert_ok!(Uniques::set_accept_ownership(RuntimeOrigin::signed(3), Some(0)));
 		assert_ok!(Uniques::transfer_ownership(RuntimeOrigin::signed(2), 0, 3));
 		assert_eq!(collections(), vec![(3, 0)]);
@@ -410,34 +442,69 @@ fn set_item_metadata_should_work() {
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 1));
 		// Cannot add metadata to unowned item
 		assert_noop!(
-			Uniques::set_metadata(RuntimeOrigin::signed(2), 0, 42, bvec![0u8; 20], false),
+			Uniques::set_metadata(RuntimeOrigin::signed(2), 0, 42, bvec![0u8; 20], false, None),
 			Error::<Test>::NoPermission,
 		);
 
 		// Successfully add metadata and take deposit
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0u8; 20], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![0u8; 20],
+			false,
+			None
+		));
 		assert_eq!(Balances::free_balance(&1), 8);
 		assert!(ItemMetadataOf::<Test>::contains_key(0, 42));
 
 		// Force origin works, too.
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![0u8; 18], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			42,
+			bvec![0u8; 18],
+			false,
+			None
+		));
 
 		// Update deposit
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0u8; 15], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![0u8; 15],
+			false,
+			None
+		));
 		assert_eq!(Balances::free_balance(&1), 13);
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0u8; 25], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![0u8; 25],
+			false,
+			None
+		));
 		assert_eq!(Balances::free_balance(&1), 3);
 
 		// Cannot over-reserve
 		assert_noop!(
-			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0u8; 40], false),
+			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0u8; 40], false, None),
 			BalancesError::<Test, _>::InsufficientBalance,
 		);
 
 		// Can't set or clear metadata once frozen
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0u8; 15], true));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![0u8; 15],
+			true,
+			None
+		));
 		assert_noop!(
-			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0u8; 15], false),
+			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0u8; 15], false, None),
 			Error::<Test, _>::Frozen,
 		);
 		assert_noop!(
@@ -446,7 +513,14 @@ fn set_item_metadata_should_work() {
 		);
 
 		// Clear Metadata
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![0u8; 15], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::root(),
+			0,
+			42,
+			bvec![0u8; 15],
+			false,
+			None
+		));
 		assert_noop!(
 			Uniques::clear_metadata(RuntimeOrigin::signed(2), 0, 42),
 			Error::<Test>::NoPermission
@@ -569,7 +643,7 @@ fn set_attribute_should_respect_freeze() {
 			bvec![1]
 		));
 
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 0, bvec![], true));
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 0, bvec![], true, None));
 		let e = Error::<Test>::Frozen;
 		assert_noop!(
 			Uniques::set_attribute(RuntimeOrigin::signed(1), 0, Some(0), bvec![0], bvec![1]),
@@ -599,25 +673,67 @@ fn force_item_status_should_work() {
 			bvec![0; 20],
 			false
 		));
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0; 20], false));
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 69, bvec![0; 20], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![0; 20],
+			false,
+			None
+		));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			69,
+			bvec![0; 20],
+			false,
+			None
+		));
 		assert_eq!(Balances::reserved_balance(1), 65);
 
 		// force item status to be free holding
 		assert_ok!(Uniques::force_item_status(RuntimeOrigin::root(), 0, 1, 1, 1, 1, true, false));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 142, 1));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 169, 2));
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 142, bvec![0; 20], false));
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 169, bvec![0; 20], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			142,
+			bvec![0; 20],
+			false,
+			None
+		));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			169,
+			bvec![0; 20],
+			false,
+			None
+		));
 		assert_eq!(Balances::reserved_balance(1), 65);
 
 		assert_ok!(Uniques::redeposit(RuntimeOrigin::signed(1), 0, bvec![0, 42, 50, 69, 100]));
 		assert_eq!(Balances::reserved_balance(1), 63);
 
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0; 20], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![0; 20],
+			false,
+			None
+		));
 		assert_eq!(Balances::reserved_balance(1), 42);
 
-		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 69, bvec![0; 20], false));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			69,
+			bvec![0; 20],
+			false,
+			None
+		));
 		assert_eq!(Balances::reserved_balance(1), 21);
 
 		assert_ok!(Uniques::set_collection_metadata(
@@ -666,15 +782,15 @@ fn approval_lifecycle_works() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
-		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
 		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
 		assert_noop!(
 			Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 3),
 			Error::<Test>::NoPermission
 		);
-		assert!(Item::<Test>::get(0, 42).unwrap().approved.is_none());
+		assert!(Item::<Test>::get(0, 42).unwrap().approvals.is_empty());
 
-		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(4), 0, 42, 2));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(4), 0, 42, 2, None));
 		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 2));
 	});
 }
@@ -685,7 +801,7 @@ fn approved_account_gets_reset_after_transfer() {
 		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
 
-		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
 		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 5));
 
 		// this shouldn't work because we have just transfered the item to another account.
@@ -708,7 +824,7 @@ fn approved_account_gets_reset_after_buy_item() {
 
 		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, item, 1));
-		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(1), 0, item, 5));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(1), 0, item, 5, None));
 
 		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(1), 0, item, Some(price), None));
 
@@ -729,7 +845,7 @@ fn cancel_approval_works() {
 		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
 
-		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
 		assert_noop!(
 			Uniques::cancel_approval(RuntimeOrigin::signed(2), 1, 42, None),
 			Error::<Test>::UnknownCollection
@@ -761,7 +877,7 @@ fn cancel_approval_works_with_admin() {
 		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
 
-		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
 		assert_noop!(
 			Uniques::cancel_approval(RuntimeOrigin::signed(1), 1, 42, None),
 			Error::<Test>::UnknownCollection
@@ -789,7 +905,7 @@ fn cancel_approval_works_with_force() {
 		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
 		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
 
-		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
 		assert_noop!(
 			Uniques::cancel_approval(RuntimeOrigin::root(), 1, 42, None),
 			Error::<Test>::UnknownCollection
@@ -1058,3 +1174,2992 @@ fn buy_item_should_work() {
 		}
 	});
 }
+
+#[test]
+fn set_collection_config_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_eq!(CollectionConfigOf::<Test>::get(0), CollectionConfig::default());
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::ForbidSelfTransfer.into()),
+			..Default::default()
+		};
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(2), 0, config),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 1, config),
+			Error::<Test>::UnknownCollection
+		);
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert!(
+			events().contains(&Event::<Test>::CollectionConfigChanged { collection: 0, config })
+		);
+		assert!(Uniques::is_collection_setting_enabled(&0, CollectionSetting::ForbidSelfTransfer));
+
+		assert_ok!(Uniques::set_collection_config(
+			RuntimeOrigin::root(),
+			0,
+			CollectionConfig::default()
+		));
+		assert!(!Uniques::is_collection_setting_enabled(&0, CollectionSetting::ForbidSelfTransfer));
+
+		let w = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::destroy(RuntimeOrigin::signed(1), 0, w));
+		assert!(!CollectionConfigOf::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn forbid_self_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		// self-transfers are allowed by default.
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 2));
+		assert_eq!(items(), vec![(2, 0, 42)]);
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::ForbidSelfTransfer.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 2),
+			Error::<Test>::CannotTransferToSelf
+		);
+		// the admin can't bypass the guard either.
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(1), 0, 42, 2),
+			Error::<Test>::CannotTransferToSelf
+		);
+
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_eq!(items(), vec![(3, 0, 42)]);
+	});
+}
+
+#[test]
+fn free_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		assert_ok!(Uniques::set_attribute(RuntimeOrigin::signed(1), 0, None, bvec![0], bvec![0]));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::FreeAttributes.into()),
+			..Default::default()
+		};
+		// only governance may sponsor the attributes.
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::root(), 0, config));
+		// ...nor may the owner switch it off again.
+		assert_noop!(
+			Uniques::set_collection_config(
+				RuntimeOrigin::signed(1),
+				0,
+				CollectionConfig::default()
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(0),
+			bvec![0],
+			bvec![0]
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		// overwriting a deposit-backed attribute refunds its deposit.
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			None,
+			bvec![0],
+			bvec![0; 10]
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(
+			attributes(0),
+			vec![(None, bvec![0], bvec![0; 10]), (Some(0), bvec![0], bvec![0])]
+		);
+		assert_eq!(Collection::<Test>::get(0).unwrap().total_deposit, 0);
+
+		assert_ok!(Uniques::clear_attribute(RuntimeOrigin::signed(1), 0, Some(0), bvec![0]));
+		assert_ok!(Uniques::clear_attribute(RuntimeOrigin::signed(1), 0, None, bvec![0]));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn escrow_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_noop!(
+			Uniques::open_escrow(RuntimeOrigin::signed(3), 0, 42, 30, 4),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 2),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3));
+		assert!(events().contains(&Event::<Test>::EscrowOpened {
+			collection: 0,
+			item: 42,
+			seller: 2,
+			buyer: 3,
+			price: 30,
+			expiry: 11,
+		}));
+		let escrow_account = Uniques::escrow_account();
+		assert_eq!(items(), vec![(escrow_account, 0, 42)]);
+		assert_eq!(Uniques::item_locked_funds(0, 42), 0);
+
+		// the item can't leave the escrow, not even by the admin.
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(1), 0, 42, 4),
+			Error::<Test>::InEscrow
+		);
+		assert_noop!(Uniques::burn(RuntimeOrigin::signed(1), 0, 42, None), Error::<Test>::InEscrow);
+		assert_noop!(
+			Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3),
+			Error::<Test>::InEscrow
+		);
+
+		assert_noop!(
+			Uniques::confirm_escrow(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::NotFunded
+		);
+		assert_noop!(
+			Uniques::fund_escrow(RuntimeOrigin::signed(4), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::fund_escrow(RuntimeOrigin::signed(3), 0, 42));
+		assert_eq!(Balances::reserved_balance(&3), 30);
+		assert_eq!(Uniques::item_locked_funds(0, 42), 30);
+		assert_noop!(
+			Uniques::fund_escrow(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::AlreadyFunded
+		);
+
+		assert_noop!(
+			Uniques::confirm_escrow(RuntimeOrigin::signed(4), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::confirm_escrow(RuntimeOrigin::signed(3), 0, 42));
+		assert_eq!(items(), vec![(escrow_account, 0, 42)]);
+		assert_ok!(Uniques::confirm_escrow(RuntimeOrigin::signed(2), 0, 42));
+		assert!(events().contains(&Event::<Test>::EscrowSettled {
+			collection: 0,
+			item: 42,
+			seller: 2,
+			buyer: 3,
+			price: 30,
+		}));
+
+		assert_eq!(items(), vec![(3, 0, 42)]);
+		assert_eq!(Balances::total_balance(&2), 30);
+		assert_eq!(Balances::total_balance(&3), 70);
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_eq!(Uniques::item_locked_funds(0, 42), 0);
+		assert!(!EscrowsOf::<Test>::contains_key(0, 42));
+		assert!(EscrowExpiries::<Test>::get(11).is_empty());
+		assert_noop!(
+			Uniques::confirm_escrow(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::NoEscrow
+		);
+	});
+}
+
+#[test]
+fn cancel_escrow_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3));
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 43, 30, 3));
+		assert_ok!(Uniques::fund_escrow(RuntimeOrigin::signed(3), 0, 42));
+		assert_eq!(Balances::reserved_balance(&3), 30);
+
+		assert_noop!(
+			Uniques::cancel_escrow(RuntimeOrigin::signed(1), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::cancel_escrow(RuntimeOrigin::signed(3), 0, 42));
+		assert!(events().contains(&Event::<Test>::EscrowCancelled { collection: 0, item: 42 }));
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_eq!(EscrowExpiries::<Test>::get(11).into_inner(), vec![(0, 43)]);
+
+		// a frozen item is still returned to the seller.
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(1), 0, 43));
+		assert_ok!(Uniques::cancel_escrow(RuntimeOrigin::root(), 0, 43));
+		assert_eq!(items(), vec![(2, 0, 42), (2, 0, 43)]);
+		assert!(EscrowExpiries::<Test>::get(11).is_empty());
+		assert_noop!(Uniques::cancel_escrow(RuntimeOrigin::root(), 0, 43), Error::<Test>::NoEscrow);
+	});
+}
+
+#[test]
+fn escrow_should_expire() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 44, 2));
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3));
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 43, 30, 3));
+		assert_noop!(
+			Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 44, 30, 3),
+			Error::<Test>::TooManyEscrows
+		);
+		assert_ok!(Uniques::fund_escrow(RuntimeOrigin::signed(3), 0, 42));
+
+		System::set_block_number(10);
+		Uniques::on_initialize(10);
+		assert_eq!(EscrowsOf::<Test>::iter().count(), 2);
+
+		System::set_block_number(11);
+		Uniques::on_initialize(11);
+		let events = events();
+		assert!(events.contains(&Event::<Test>::EscrowExpired { collection: 0, item: 42 }));
+		assert!(events.contains(&Event::<Test>::EscrowExpired { collection: 0, item: 43 }));
+		assert_eq!(items(), vec![(2, 0, 42), (2, 0, 43), (2, 0, 44)]);
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_eq!(EscrowsOf::<Test>::iter().count(), 0);
+		assert!(!EscrowExpiries::<Test>::contains_key(11));
+
+		// there is room for new escrows again.
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 44, 30, 3));
+	});
+}
+
+#[test]
+fn destroy_should_refund_escrows() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3));
+		assert_ok!(Uniques::fund_escrow(RuntimeOrigin::signed(3), 0, 42));
+
+		let w = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Uniques::destroy(RuntimeOrigin::signed(1), 0, w));
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_eq!(EscrowsOf::<Test>::iter().count(), 0);
+
+		// the stale expiry entry is skipped.
+		Uniques::on_initialize(11);
+		assert!(!events().contains(&Event::<Test>::EscrowExpired { collection: 0, item: 42 }));
+	});
+}
+
+#[test]
+fn is_collection_owner_should_work() {
+	new_test_ext().execute_with(|| {
+		assert!(!Uniques::is_collection_owner(0, &1));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert!(Uniques::is_collection_owner(0, &1));
+		assert!(!Uniques::is_collection_owner(0, &2));
+
+		assert_ok!(Uniques::set_accept_ownership(RuntimeOrigin::signed(2), Some(0)));
+		assert_ok!(Uniques::transfer_ownership(RuntimeOrigin::signed(1), 0, 2));
+		assert!(!Uniques::is_collection_owner(0, &1));
+		assert!(Uniques::is_collection_owner(0, &2));
+	});
+}
+
+#[test]
+fn set_role_many_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 2, 2, true));
+
+		// the whole batch fails if any collection isn't owned by the sender.
+		let assignments = bvec![
+			(0, CollectionRole::Freezer, 3),
+			(1, CollectionRole::Freezer, 3),
+			(2, CollectionRole::Freezer, 3),
+		];
+		assert_noop!(
+			Uniques::set_role_many(RuntimeOrigin::signed(1), assignments),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_role_many(RuntimeOrigin::signed(1), bvec![(3, CollectionRole::Admin, 3)]),
+			Error::<Test>::UnknownCollection
+		);
+
+		let assignments = bvec![
+			(0, CollectionRole::Freezer, 3),
+			(1, CollectionRole::Freezer, 3),
+			(1, CollectionRole::Issuer, 4),
+		];
+		assert_ok!(Uniques::set_role_many(RuntimeOrigin::signed(1), assignments));
+		assert!(events().contains(&Event::<Test>::RolesSet {
+			owner: 1,
+			assignments: vec![
+				(0, CollectionRole::Freezer, 3),
+				(1, CollectionRole::Freezer, 3),
+				(1, CollectionRole::Issuer, 4),
+			],
+		}));
+
+		let details = Collection::<Test>::get(0).unwrap();
+		assert_eq!((details.issuer, details.admin, details.freezer), (1, 1, 3));
+		let details = Collection::<Test>::get(1).unwrap();
+		assert_eq!((details.issuer, details.admin, details.freezer), (4, 1, 3));
+
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(4), 1, 42, 2));
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(3), 1, 42));
+	});
+}
+
+#[test]
+fn canonical_collection_id_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Uniques::canonical_collection_id(7), Some(7));
+		assert_eq!(Uniques::collection_from_canonical(7), Some(7));
+		assert_eq!(Uniques::collection_from_canonical(u32::MAX as u128 + 1), None);
+	});
+}
+
+#[test]
+fn refresh_item_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+
+		// the collection stops being free-holding, so deposits are now due.
+		assert_ok!(Uniques::force_item_status(RuntimeOrigin::root(), 0, 1, 1, 1, 1, false, false));
+		assert_noop!(
+			Uniques::refresh_item_deposit(RuntimeOrigin::signed(2), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::refresh_item_deposit(RuntimeOrigin::signed(1), 0, 44),
+			Error::<Test>::UnknownItem
+		);
+		assert_ok!(Uniques::refresh_item_deposit(RuntimeOrigin::signed(1), 0, 42));
+		assert!(events().contains(&Event::<Test>::ItemDepositRefreshed {
+			collection: 0,
+			item: 42,
+			old: 0,
+			new: 1,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Collection::<Test>::get(0).unwrap().total_deposit, 1);
+
+		assert_ok!(Uniques::force_item_status(RuntimeOrigin::root(), 0, 1, 1, 1, 1, true, false));
+		assert_ok!(Uniques::refresh_item_deposit(RuntimeOrigin::root(), 0, 42));
+		assert!(events().contains(&Event::<Test>::ItemDepositRefreshed {
+			collection: 0,
+			item: 42,
+			old: 1,
+			new: 0,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Collection::<Test>::get(0).unwrap().total_deposit, 0);
+
+		// an owner who can't afford the new deposit can't be drained.
+		assert_ok!(Uniques::force_item_status(RuntimeOrigin::root(), 0, 3, 3, 3, 3, false, false));
+		assert_noop!(
+			Uniques::refresh_item_deposit(RuntimeOrigin::signed(3), 0, 43),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn offchain_metadata_only_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 1));
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::OffchainMetadataOnly.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		let inline_data: [&[u8]; 6] =
+			[b"{\"name\":\"x\"}", b"", b"://x", b"ipfs://", b"1pfs://x", b"ipfs://a b"];
+		for inline in inline_data {
+			assert_noop!(
+				Uniques::set_metadata(
+					RuntimeOrigin::signed(1),
+					0,
+					42,
+					inline.to_vec().try_into().unwrap(),
+					false,
+					None
+				),
+				Error::<Test>::InlineMetadataForbidden
+			);
+			assert_noop!(
+				Uniques::set_collection_metadata(
+					RuntimeOrigin::root(),
+					0,
+					inline.to_vec().try_into().unwrap(),
+					false
+				),
+				Error::<Test>::InlineMetadataForbidden
+			);
+		}
+
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			b"ipfs://QmHash".to_vec().try_into().unwrap(),
+			false,
+			None
+		));
+		assert_ok!(Uniques::set_collection_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			b"https://example.com/collection.json".to_vec().try_into().unwrap(),
+			false
+		));
+
+		// other collections are unaffected.
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, false));
+		assert_ok!(Uniques::set_collection_metadata(
+			RuntimeOrigin::signed(1),
+			1,
+			b"{}".to_vec().try_into().unwrap(),
+			false
+		));
+	});
+}
+
+#[test]
+fn mint_next_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_noop!(
+			Uniques::mint_next(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::SequentialIdsDisabled
+		);
+		assert_eq!(Uniques::next_item_id(0), None);
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::SequentialIds.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_noop!(
+			Uniques::mint_next(RuntimeOrigin::signed(2), 0, 2),
+			Error::<Test>::NoPermission
+		);
+
+		assert_eq!(Uniques::next_item_id(0), Some(0));
+		assert_ok!(Uniques::mint_next(RuntimeOrigin::signed(1), 0, 2));
+		assert!(events().contains(&Event::<Test>::Issued { collection: 0, item: 0, owner: 2 }));
+		assert_ok!(Uniques::mint_next(RuntimeOrigin::signed(1), 0, 3));
+		assert!(events().contains(&Event::<Test>::Issued { collection: 0, item: 1, owner: 3 }));
+
+		// burned ids are not reused.
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(3), 0, 1, None));
+		assert_ok!(Uniques::mint_next(RuntimeOrigin::signed(1), 0, 3));
+		assert!(events().contains(&Event::<Test>::Issued { collection: 0, item: 2, owner: 3 }));
+		assert_eq!(items(), vec![(2, 0, 0), (3, 0, 2)]);
+		assert_eq!(Uniques::next_item_id(0), Some(3));
+
+		// once the ids are exhausted, nothing more can be minted.
+		NextItemIdOf::<Test>::insert(0, u32::MAX);
+		assert_noop!(
+			Uniques::mint_next(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::NoAvailableItemId
+		);
+	});
+}
+
+#[test]
+fn transfer_policy_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		SanctionedAccount::set(Some(3));
+
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3),
+			DispatchError::Other("sanctioned")
+		);
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 4, None));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(4), 0, 42, 3),
+			DispatchError::Other("sanctioned")
+		);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 4));
+
+		SanctionedAccount::set(Some(4));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(4), 0, 42, 2),
+			DispatchError::Other("sanctioned")
+		);
+
+		SanctionedAccount::set(None);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(4), 0, 42, 2));
+		assert_eq!(items(), vec![(2, 0, 42)]);
+	});
+}
+
+#[test]
+fn max_mints_per_block_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { max_mints_per_block: Some(2), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 1, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 2, 2));
+		assert_noop!(
+			Uniques::mint(RuntimeOrigin::signed(1), 0, 3, 2),
+			Error::<Test>::MintRateLimited
+		);
+
+		// other collections are unaffected.
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 1, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 2, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 3, 2));
+
+		// the limit applies afresh to every block.
+		System::set_block_number(2);
+		Uniques::on_initialize(2);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 3, 2));
+		assert_eq!(MintsThisBlockOf::<Test>::get(0), 1);
+	});
+}
+
+#[test]
+fn burn_policy_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		for item in 1..=3 {
+			assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, item, 2));
+			assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, item, 3, None));
+		}
+
+		// `OwnerOnly` is the default.
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(3), 0, 1, None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(4), 0, 1, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 1, None));
+		assert!(events().contains(&Event::<Test>::Burned {
+			collection: 0,
+			item: 1,
+			owner: 2,
+			burner: Some(2),
+			via_sink: false,
+		}));
+
+		let config =
+			CollectionConfig { burn_policy: BurnPolicy::ApprovedOrOwner, ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(4), 0, 2, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(3), 0, 2, None));
+		assert!(events().contains(&Event::<Test>::Burned {
+			collection: 0,
+			item: 2,
+			owner: 2,
+			burner: Some(3),
+			via_sink: false,
+		}));
+
+		let config = CollectionConfig { burn_policy: BurnPolicy::Anyone, ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(4), 0, 3, Some(3)),
+			Error::<Test>::WrongOwner
+		);
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(4), 0, 3, None));
+		assert!(events().contains(&Event::<Test>::Burned {
+			collection: 0,
+			item: 3,
+			owner: 2,
+			burner: Some(4),
+			via_sink: false,
+		}));
+		assert_eq!(items(), vec![]);
+	});
+}
+
+#[test]
+fn check_deposits_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::set_collection_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			bvec![0u8; 10],
+			false
+		));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![0u8; 10],
+			false,
+			None
+		));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![0]
+		));
+		assert_ok!(Uniques::check_deposits(0));
+		assert_ok!(Uniques::do_try_state());
+
+		// the recorded total falls short of the individual deposits.
+		Collection::<Test>::mutate(0, |details| details.as_mut().unwrap().total_deposit = 1);
+		assert_noop!(Uniques::check_deposits(0), Error::<Test>::DepositMismatch);
+		assert!(Uniques::do_try_state().is_err());
+
+		// the owner has less reserved than recorded.
+		Collection::<Test>::mutate(0, |details| details.as_mut().unwrap().total_deposit = 100);
+		assert_noop!(Uniques::check_deposits(0), Error::<Test>::DepositMismatch);
+		assert!(Uniques::do_try_state().is_err());
+
+		assert_noop!(Uniques::check_deposits(1), Error::<Test>::UnknownCollection);
+	});
+}
+
+#[test]
+fn max_approvals_per_item_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, Some(2)));
+		assert!(events().contains(&Event::<Test>::ApprovedTransfer {
+			collection: 0,
+			item: 42,
+			owner: 2,
+			delegate: 3,
+			deadline: Some(3),
+		}));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 4, None));
+		// re-approving a delegate doesn't take up another slot.
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 4, None));
+		assert_noop!(
+			Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 5, None),
+			Error::<Test>::TooManyApprovals
+		);
+
+		// cancelling an approval frees its slot.
+		assert_ok!(Uniques::cancel_approval(RuntimeOrigin::signed(2), 0, 42, Some(4)));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 5, None));
+
+		// as does the expiry of an approval.
+		System::set_block_number(4);
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 3),
+			Error::<Test>::ApprovalExpired
+		);
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 6, None));
+		assert!(!Item::<Test>::get(0, 42).unwrap().approvals.contains_key(&3));
+
+		// cancelling without a delegate clears all approvals.
+		assert_ok!(Uniques::cancel_approval(RuntimeOrigin::signed(2), 0, 42, None));
+		assert!(Item::<Test>::get(0, 42).unwrap().approvals.is_empty());
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(5), 0, 42, 5),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn item_trading_state_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(
+			Uniques::item_trading_state(0, 42),
+			ItemTradingState { listing: None, escrow: None }
+		);
+
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(2), 0, 42, Some(20), Some(3)));
+		assert_eq!(
+			Uniques::item_trading_state(0, 42),
+			ItemTradingState { listing: Some((20, Some(3))), escrow: None }
+		);
+
+		// opening an escrow transfers the item, which removes the listing.
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3));
+		assert_eq!(
+			Uniques::item_trading_state(0, 42),
+			ItemTradingState { listing: None, escrow: Some((3, 30)) }
+		);
+	});
+}
+
+#[test]
+fn mint_min_stake_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { mint_min_stake: Some(40), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert!(events().contains(&Event::<Test>::MintStaked {
+			collection: 0,
+			item: 42,
+			who: 1,
+			amount: 40,
+			release_at: 6,
+		}));
+		assert_eq!(Balances::reserved_balance(&1), 40);
+
+		System::set_block_number(3);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+		assert_eq!(Balances::reserved_balance(&1), 80);
+		assert_noop!(
+			Uniques::mint(RuntimeOrigin::signed(1), 0, 44, 2),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+
+		// stakes are released once their cooldown is over.
+		Uniques::on_initialize(6);
+		assert_eq!(Balances::reserved_balance(&1), 40);
+		assert!(events().contains(&Event::<Test>::MintStakeReleased { who: 1, amount: 40 }));
+		Uniques::on_initialize(8);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+
+		// mints not made by an account don't require a stake.
+		assert_ok!(<Uniques as Mutate<_>>::mint_into(&0, &44, &2));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn set_item_name_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 69, 2));
+		assert_noop!(
+			Uniques::set_item_name(RuntimeOrigin::signed(2), 0, 42, bvec![0]),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 7, bvec![0]),
+			Error::<Test>::UnknownItem
+		);
+
+		// without `UniqueItemNames`, names may be shared.
+		assert_ok!(Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 42, bvec![0]));
+		assert!(events().contains(&Event::<Test>::ItemNameSet {
+			collection: 0,
+			item: 42,
+			name: bvec![0]
+		}));
+		assert_ok!(Uniques::set_item_name(RuntimeOrigin::root(), 0, 69, bvec![0]));
+		assert_eq!(Uniques::item_name(0, 69), Some(vec![0]));
+		assert_eq!(Uniques::item_by_name(0, vec![0]), None);
+
+		// names given so far would not be indexed.
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::UniqueItemNames.into()),
+			..Default::default()
+		};
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::ItemsNamed
+		);
+		for item in [42, 69] {
+			assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, item, None));
+			assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, item, 2));
+		}
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 42, bvec![1]));
+		assert_eq!(Uniques::item_by_name(0, vec![1]), Some(42));
+		assert_noop!(
+			Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 69, bvec![1]),
+			Error::<Test>::NameTaken
+		);
+		// an item may be renamed to its own name.
+		assert_ok!(Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 42, bvec![1]));
+
+		// renaming frees the old name.
+		assert_ok!(Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 42, bvec![2]));
+		assert_eq!(Uniques::item_by_name(0, vec![1]), None);
+		assert_ok!(Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 69, bvec![1]));
+
+		// burning frees the name, too.
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_eq!(Uniques::item_name(0, 42), None);
+		assert_eq!(Uniques::item_by_name(0, vec![2]), None);
+		assert_eq!(Uniques::item_by_name(0, vec![1]), Some(69));
+	});
+}
+
+#[test]
+fn recurring_lock_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_noop!(
+			Uniques::lock_collection_recurring(RuntimeOrigin::signed(2), 0, 2, 5),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::lock_collection_recurring(RuntimeOrigin::signed(1), 0, 5, 5),
+			Error::<Test>::InvalidLockPeriod
+		);
+		assert_noop!(
+			Uniques::lock_collection_recurring(RuntimeOrigin::signed(1), 0, 0, 5),
+			Error::<Test>::InvalidLockPeriod
+		);
+
+		assert_ok!(Uniques::lock_collection_recurring(RuntimeOrigin::signed(1), 0, 2, 5));
+		let events = events();
+		assert!(events.contains(&Event::<Test>::RecurringLockSet {
+			collection: 0,
+			duration: 2,
+			period: 5
+		}));
+		assert!(events.contains(&Event::<Test>::CollectionFrozen { collection: 0 }));
+		assert_noop!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3), Error::<Test>::Frozen);
+
+		// thawed after `duration` blocks, frozen again once the `period` is over.
+		Uniques::on_initialize(3);
+		assert!(events().contains(&Event::<Test>::CollectionThawed { collection: 0 }));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		Uniques::on_initialize(6);
+		assert!(events().contains(&Event::<Test>::CollectionFrozen { collection: 0 }));
+		assert_noop!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 2), Error::<Test>::Frozen);
+		assert_eq!(RecurringLockTransitions::<Test>::get(8).into_inner(), vec![0]);
+
+		// the number of recurring locks is bounded.
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 2, 1, true));
+		assert_ok!(Uniques::lock_collection_recurring(RuntimeOrigin::signed(1), 1, 2, 5));
+		assert_noop!(
+			Uniques::lock_collection_recurring(RuntimeOrigin::signed(1), 2, 2, 5),
+			Error::<Test>::TooManyRecurringLocks
+		);
+
+		// once cancelled, the collection is no longer thawed or frozen.
+		assert_ok!(Uniques::cancel_recurring_lock(RuntimeOrigin::signed(1), 0));
+		assert!(events().contains(&Event::<Test>::RecurringLockCancelled { collection: 0 }));
+		assert!(RecurringLockTransitions::<Test>::get(8).is_empty());
+		Uniques::on_initialize(8);
+		assert_noop!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 2), Error::<Test>::Frozen);
+		assert_noop!(
+			Uniques::cancel_recurring_lock(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoRecurringLock
+		);
+		assert_ok!(Uniques::lock_collection_recurring(RuntimeOrigin::signed(1), 2, 2, 5));
+	});
+}
+
+#[test]
+fn settings_locked_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert!(!Uniques::are_settings_locked(0));
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::SettingsLocked.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert!(Uniques::are_settings_locked(0));
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, Default::default()),
+			Error::<Test>::SettingsLocked
+		);
+
+		// governance may still step in.
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::root(), 0, Default::default()));
+		assert!(!Uniques::are_settings_locked(0));
+	});
+}
+
+#[test]
+fn freeze_collection_content_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![0], false, None));
+		assert_noop!(
+			Uniques::freeze_collection_content(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::freeze_collection_content(RuntimeOrigin::signed(1), 0));
+		assert!(events().contains(&Event::<Test>::CollectionContentFrozen { collection: 0 }));
+
+		assert_noop!(
+			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![1], false, None),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Uniques::clear_metadata(RuntimeOrigin::signed(1), 0, 42),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Uniques::set_collection_metadata(RuntimeOrigin::signed(1), 0, bvec![0], false),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Uniques::set_attribute(RuntimeOrigin::signed(1), 0, Some(42), bvec![0], bvec![0]),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 42, bvec![0]),
+			Error::<Test>::Frozen
+		);
+		// the owner may not switch it off again.
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, Default::default()),
+			Error::<Test>::Frozen
+		);
+
+		// items are still transferable.
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		// and governance may still change the content.
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![1], false, None));
+	});
+}
+
+#[test]
+fn reserved_item_id_ceiling_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { reserved_item_id_ceiling: Some(10), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		// only the issuer may mint within the reserved range.
+		assert_noop!(<Uniques as Mutate<_>>::mint_into(&0, &10, &2), Error::<Test>::ReservedItemId);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 10, 2));
+		assert_ok!(<Uniques as Mutate<_>>::mint_into(&0, &11, &2));
+		assert_eq!(items(), vec![(2, 0, 10), (2, 0, 11)]);
+	});
+}
+
+#[test]
+fn wrap_item_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 69, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 70, 3));
+
+		assert_noop!(
+			Uniques::wrap_item(RuntimeOrigin::signed(2), (0, 42), (0, 42)),
+			Error::<Test>::WrapIntoSelf
+		);
+		assert_noop!(
+			Uniques::wrap_item(RuntimeOrigin::signed(2), (0, 42), (1, 70)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::wrap_item(RuntimeOrigin::signed(3), (1, 70), (0, 42)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::wrap_item(RuntimeOrigin::signed(2), (0, 42), (1, 69)));
+		assert!(events().contains(&Event::<Test>::ItemWrapped {
+			collection: 1,
+			item: 69,
+			parent_collection: 0,
+			parent_item: 42,
+		}));
+		let wrap_account = Uniques::wrap_account((0, 42));
+		assert_eq!(Uniques::owner(1, 69), Some(wrap_account));
+
+		// the wrapped item doesn't move, and the parent can't be burned.
+		assert_noop!(Uniques::transfer(RuntimeOrigin::signed(1), 1, 69, 3), Error::<Test>::Wrapped);
+		assert_noop!(Uniques::burn(RuntimeOrigin::signed(1), 1, 69, None), Error::<Test>::Wrapped);
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None),
+			Error::<Test>::HasWrappedItems
+		);
+		let w = Collection::<Test>::get(1).unwrap().destroy_witness();
+		assert_noop!(Uniques::destroy(RuntimeOrigin::signed(1), 1, w), Error::<Test>::Wrapped);
+
+		// the wrapped item goes along with its parent.
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_noop!(
+			Uniques::unwrap_item(RuntimeOrigin::signed(2), (1, 69)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::unwrap_item(RuntimeOrigin::signed(3), (1, 69)));
+		assert!(events().contains(&Event::<Test>::ItemUnwrapped {
+			collection: 1,
+			item: 69,
+			owner: 3
+		}));
+		assert_eq!(Uniques::owner(1, 69), Some(3));
+		assert!(!WrappedCountOf::<Test>::contains_key(0, 42));
+		assert_noop!(
+			Uniques::unwrap_item(RuntimeOrigin::signed(3), (1, 69)),
+			Error::<Test>::NotWrapped
+		);
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(3), 0, 42, None));
+	});
+}
+
+#[test]
+fn first_transfer_approval_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::RequireFirstTransferApproval.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3),
+			Error::<Test>::FirstTransferNotApproved
+		);
+		assert_noop!(
+			Uniques::approve_first_transfer(RuntimeOrigin::signed(2), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::approve_first_transfer(RuntimeOrigin::signed(1), 0, 43),
+			Error::<Test>::UnknownItem
+		);
+		assert_ok!(Uniques::approve_first_transfer(RuntimeOrigin::signed(1), 0, 42));
+		assert!(
+			events().contains(&Event::<Test>::FirstTransferApproved { collection: 0, item: 42 })
+		);
+
+		// once approved, the item may be transferred freely.
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
+
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(4), 0, 42, None));
+		assert!(!FirstTransferApprovedOf::<Test>::contains_key(0, 42));
+	});
+}
+
+#[test]
+fn collection_deposit_tiers_should_work() {
+	new_test_ext().execute_with(|| {
+		FreeCollections::set(1);
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::reserved_balance(&1), 2);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 1, 1));
+		assert_eq!(Balances::reserved_balance(&1), 6);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 2, 1));
+		assert_eq!(Balances::reserved_balance(&1), 12);
+		assert_eq!(OwnedCollectionsCountOf::<Test>::get(1), 3);
+
+		// destroying refunds the deposit paid for the collection.
+		let w = Collection::<Test>::get(2).unwrap().destroy_witness();
+		assert_ok!(Uniques::destroy(RuntimeOrigin::signed(1), 2, w));
+		assert_eq!(Balances::reserved_balance(&1), 6);
+		assert_eq!(OwnedCollectionsCountOf::<Test>::get(1), 2);
+
+		// handing a collection over moves it to the new owner's count.
+		assert_ok!(Uniques::set_accept_ownership(RuntimeOrigin::signed(2), Some(1)));
+		assert_ok!(Uniques::transfer_ownership(RuntimeOrigin::signed(1), 1, 2));
+		assert_eq!(OwnedCollectionsCountOf::<Test>::get(1), 1);
+		assert_eq!(OwnedCollectionsCountOf::<Test>::get(2), 1);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(2), 3, 2));
+		assert_eq!(Balances::reserved_balance(&2), 4 + 4);
+	});
+}
+
+#[test]
+fn freeze_after_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+
+		// default collections are unaffected.
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 43, 3));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 43, 2));
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::FreezeAfterTransfer.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert!(events().contains(&Event::<Test>::ItemTransferLocked { collection: 0, item: 42 }));
+		assert_noop!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 4), Error::<Test>::Frozen);
+
+		// the item is frozen for good, unless it's thawed.
+		assert_ok!(Uniques::thaw(RuntimeOrigin::signed(1), 0, 42));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
+		assert_eq!(items(), vec![(2, 0, 43), (4, 0, 42)]);
+	});
+}
+
+#[test]
+fn owners_of_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 69, 3));
+
+		assert_eq!(
+			Uniques::owners_of(0, vec![69, 7, 42]),
+			Ok(vec![(69, Some(3)), (7, None), (42, Some(2))])
+		);
+		assert_eq!(Uniques::owners_of(1, vec![42]), Ok(vec![(42, None)]));
+		assert_eq!(
+			Uniques::owners_of(0, vec![1, 2, 3, 4]),
+			Err(Error::<Test>::TooManyItems.into())
+		);
+	});
+}
+
+#[test]
+fn unrevealed_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 69, 2));
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 69, bvec![69], false, None));
+
+		assert_noop!(
+			Uniques::set_unrevealed_metadata(RuntimeOrigin::signed(2), 0, bvec![0]),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_unrevealed_metadata(RuntimeOrigin::signed(1), 0, bvec![0]));
+		assert!(events()
+			.contains(&Event::<Test>::UnrevealedMetadataSet { collection: 0, data: bvec![0] }));
+		assert_eq!(Uniques::item_metadata(0, 42), Some(vec![0]));
+		assert_eq!(<Uniques as Inspect<_>>::attribute(&0, &42, &[]), Some(vec![0]));
+		assert_eq!(Uniques::item_metadata(0, 69), Some(vec![69]));
+		assert_eq!(Uniques::item_metadata(0, 7), None);
+
+		assert_noop!(
+			Uniques::reveal_collection(RuntimeOrigin::signed(2), 0, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::reveal_collection(RuntimeOrigin::signed(1), 0, None));
+		assert!(events().contains(&Event::<Test>::CollectionRevealed { collection: 0 }));
+		assert_eq!(Uniques::item_metadata(0, 42), None);
+		assert_eq!(Uniques::item_metadata(0, 69), Some(vec![69]));
+		assert_noop!(
+			Uniques::reveal_collection(RuntimeOrigin::signed(1), 0, None),
+			Error::<Test>::AlreadyRevealed
+		);
+		assert_noop!(
+			Uniques::set_unrevealed_metadata(RuntimeOrigin::signed(1), 0, bvec![0]),
+			Error::<Test>::AlreadyRevealed
+		);
+	});
+}
+
+#[test]
+fn marketplace_policy_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		let allowlist =
+			MarketplacePolicy::Allowlist(BoundedBTreeSet::try_from([3].into()).unwrap());
+		assert_noop!(
+			Uniques::set_marketplace_policy(RuntimeOrigin::signed(2), 0, allowlist.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_marketplace_policy(RuntimeOrigin::signed(1), 0, allowlist.clone()));
+		assert!(events()
+			.contains(&Event::<Test>::MarketplacePolicySet { collection: 0, policy: allowlist }));
+		assert_noop!(
+			Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 4, None),
+			Error::<Test>::MarketplaceNotAllowed
+		);
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
+
+		let denylist = MarketplacePolicy::Denylist(BoundedBTreeSet::try_from([3].into()).unwrap());
+		assert_ok!(Uniques::set_marketplace_policy(RuntimeOrigin::signed(1), 0, denylist));
+		assert_noop!(
+			Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None),
+			Error::<Test>::MarketplaceNotAllowed
+		);
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 4, None));
+
+		assert_ok!(Uniques::set_marketplace_policy(
+			RuntimeOrigin::root(),
+			0,
+			MarketplacePolicy::Any
+		));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
+	});
+}
+
+#[test]
+fn item_transfer_count_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(Uniques::item_transfer_count(0, 42), 0);
+
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 2));
+		assert_eq!(Uniques::item_transfer_count(0, 42), 2);
+
+		// the count starts over once the item is burned and minted again.
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(Uniques::item_transfer_count(0, 42), 0);
+	});
+}
+
+#[test]
+fn reveal_commitment_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		let root: BoundedVec<u8, _> = bvec![1, 2, 3];
+		let hash = <Test as frame_system::Config>::Hashing::hash(&root);
+
+		assert_noop!(
+			Uniques::commit_reveal_hash(RuntimeOrigin::signed(2), 0, hash),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::commit_reveal_hash(RuntimeOrigin::signed(1), 0, hash));
+		assert!(events().contains(&Event::<Test>::RevealCommitted { collection: 0, hash }));
+		assert_noop!(
+			Uniques::commit_reveal_hash(RuntimeOrigin::signed(1), 0, H256::zero()),
+			Error::<Test>::AlreadyCommitted
+		);
+
+		assert_noop!(
+			Uniques::reveal_collection(RuntimeOrigin::signed(1), 0, None),
+			Error::<Test>::RevealMismatch
+		);
+		assert_noop!(
+			Uniques::reveal_collection(RuntimeOrigin::signed(1), 0, Some(bvec![3, 2, 1])),
+			Error::<Test>::RevealMismatch
+		);
+		assert_ok!(Uniques::reveal_collection(RuntimeOrigin::signed(1), 0, Some(root)));
+		assert!(events().contains(&Event::<Test>::CollectionRevealed { collection: 0 }));
+		assert_noop!(
+			Uniques::commit_reveal_hash(RuntimeOrigin::root(), 0, hash),
+			Error::<Test>::AlreadyRevealed
+		);
+	});
+}
+
+#[test]
+fn force_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(1), 0, 42));
+		assert_ok!(Uniques::freeze_collection(RuntimeOrigin::signed(1), 0));
+		SanctionedAccount::set(Some(4));
+
+		assert_noop!(
+			Uniques::force_transfer(RuntimeOrigin::signed(1), 0, 42, 4),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Uniques::force_transfer(RuntimeOrigin::root(), 0, 42, 4));
+		assert_eq!(items(), vec![(4, 0, 42)]);
+		assert!(events().contains(&Event::<Test>::Transferred {
+			collection: 0,
+			item: 42,
+			from: 2,
+			to: 4
+		}));
+		assert!(events().contains(&Event::<Test>::ItemForceTransferred { collection: 0, item: 42 }));
+		assert!(Item::<Test>::get(0, 42).unwrap().approvals.is_empty());
+		SanctionedAccount::set(None);
+	});
+}
+
+#[test]
+fn metadata_write_once_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::MetadataWriteOnce.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![42], false, None));
+		assert!(events().contains(&Event::<Test>::MetadataSet {
+			collection: 0,
+			item: 42,
+			data: bvec![42],
+			is_frozen: true,
+			storage_proof: None,
+		}));
+		assert_noop!(
+			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![69], false, None),
+			Error::<Test>::Frozen
+		);
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![69], false, None));
+		assert_eq!(Uniques::item_metadata(0, 42), Some(vec![69]));
+	});
+}
+
+#[test]
+fn required_membership_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		let config = CollectionConfig { required_membership: Some(2), ..Default::default() };
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::UnknownCollection
+		);
+		let config = CollectionConfig { required_membership: Some(1), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3),
+			Error::<Test>::DestinationNotMember
+		);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 7, 3));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_eq!(items(), vec![(3, 0, 42), (3, 1, 7)]);
+	});
+}
+
+#[test]
+fn freeze_functions_should_return_their_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_noop!(Uniques::do_freeze_item(0, 42, Some(2)), Error::<Test>::NoPermission);
+		assert_eq!(Uniques::do_freeze_item(0, 42, None), Ok(<() as WeightInfo>::freeze()));
+		assert_eq!(Uniques::do_thaw_item(0, 42, Some(1)), Ok(<() as WeightInfo>::thaw()));
+		assert_eq!(
+			Uniques::do_freeze_collection(0, None),
+			Ok(<() as WeightInfo>::freeze_collection())
+		);
+		assert!(Collection::<Test>::get(0).unwrap().is_frozen);
+		assert_eq!(
+			Uniques::do_thaw_collection(0, Some(1)),
+			Ok(<() as WeightInfo>::thaw_collection())
+		);
+		assert!(!Collection::<Test>::get(0).unwrap().is_frozen);
+	});
+}
+
+#[test]
+fn max_total_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { max_total_attributes: Some(2), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_ok!(Uniques::set_attribute(RuntimeOrigin::signed(1), 0, None, bvec![0], bvec![0]));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![0]
+		));
+		assert_noop!(
+			Uniques::set_attribute(RuntimeOrigin::signed(1), 0, Some(42), bvec![1], bvec![0]),
+			Error::<Test>::CollectionAttributeLimitReached
+		);
+		// overwriting an existing attribute doesn't count towards the limit.
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![1]
+		));
+
+		assert_ok!(Uniques::clear_attribute(RuntimeOrigin::signed(1), 0, None, bvec![0]));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![1],
+			bvec![0]
+		));
+	});
+}
+
+#[test]
+fn approval_ttl_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { approval_ttl: Some(5), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
+		assert!(events().contains(&Event::<Test>::ApprovedTransfer {
+			collection: 0,
+			item: 42,
+			owner: 2,
+			delegate: 3,
+			deadline: Some(6)
+		}));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 4, Some(10)));
+		assert!(events().contains(&Event::<Test>::ApprovedTransfer {
+			collection: 0,
+			item: 42,
+			owner: 2,
+			delegate: 4,
+			deadline: Some(6)
+		}));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 4, Some(2)));
+		assert!(events().contains(&Event::<Test>::ApprovedTransfer {
+			collection: 0,
+			item: 42,
+			owner: 2,
+			delegate: 4,
+			deadline: Some(3)
+		}));
+
+		System::set_block_number(7);
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 3),
+			Error::<Test>::ApprovalExpired
+		);
+	});
+}
+
+#[test]
+fn snapshot_and_restore_collection_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![42], false, None));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![0]
+		));
+		assert_eq!(Uniques::snapshot_collection(1), None);
+		let snapshot = Uniques::snapshot_collection(0).unwrap();
+
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 69, 2));
+		assert_ok!(Uniques::clear_metadata(RuntimeOrigin::signed(1), 0, 42));
+		assert_ok!(Uniques::set_attribute(RuntimeOrigin::signed(1), 0, None, bvec![1], bvec![1]));
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(1), 0, 4, 4, 4));
+
+		Uniques::restore_collection(snapshot.clone());
+		assert_eq!(items(), vec![(2, 0, 42)]);
+		assert_eq!(Uniques::item_metadata(0, 42), Some(vec![42]));
+		assert_eq!(<Uniques as Inspect<_>>::collection_attribute(&0, &[1]), None);
+		assert_eq!(Collection::<Test>::get(0).unwrap().admin, 1);
+		assert_eq!(Uniques::snapshot_collection(0), Some(snapshot));
+	});
+}
+
+#[test]
+fn transfer_policy_should_apply_to_mints() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		SanctionedAccount::set(Some(3));
+
+		assert_noop!(
+			Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 3),
+			DispatchError::Other("sanctioned")
+		);
+		assert_noop!(
+			<Uniques as Mutate<_>>::mint_into(&0, &42, &3),
+			DispatchError::Other("sanctioned")
+		);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		SanctionedAccount::set(None);
+	});
+}
+
+#[test]
+fn make_item_soulbound_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_noop!(
+			Uniques::make_item_soulbound(RuntimeOrigin::signed(2), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::make_item_soulbound(RuntimeOrigin::signed(1), 0, 42));
+		assert!(events().contains(&Event::<Test>::ItemMadeSoulbound { collection: 0, item: 42 }));
+		assert_noop!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3), Error::<Test>::Frozen);
+		assert_noop!(Uniques::thaw(RuntimeOrigin::signed(1), 0, 42), Error::<Test>::ItemSoulbound);
+
+		// soulbound items can still be burned.
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+	});
+}
+
+#[test]
+fn issuer_manages_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(1), 0, 2, 1, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_noop!(
+			Uniques::set_metadata(RuntimeOrigin::signed(2), 0, 42, bvec![42], false, None),
+			Error::<Test>::NoPermission
+		);
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::IssuerManagesMetadata.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_noop!(
+			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![42], false, None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_collection_metadata(RuntimeOrigin::signed(1), 0, bvec![42], false),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(2), 0, 42, bvec![42], false, None));
+		assert_ok!(Uniques::set_collection_metadata(RuntimeOrigin::signed(2), 0, bvec![42], false));
+		assert_eq!(Uniques::item_metadata(0, 42), Some(vec![42]));
+
+		assert_noop!(
+			Uniques::clear_metadata(RuntimeOrigin::signed(1), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::clear_metadata(RuntimeOrigin::signed(2), 0, 42));
+		assert_ok!(Uniques::clear_collection_metadata(RuntimeOrigin::signed(2), 0));
+	});
+}
+
+#[test]
+fn mint_and_list_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_noop!(
+			Uniques::mint_and_list(RuntimeOrigin::signed(2), 0, 42, 10, None),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Uniques::mint_and_list(RuntimeOrigin::signed(1), 0, 42, 10, Some(2)));
+		assert_eq!(items(), vec![(1, 0, 42)]);
+		assert_eq!(ItemPriceOf::<Test>::get(0, 42), Some((10, Some(2))));
+		assert!(events().contains(&Event::<Test>::Issued { collection: 0, item: 42, owner: 1 }));
+		assert!(events().contains(&Event::<Test>::ItemPriceSet {
+			collection: 0,
+			item: 42,
+			price: 10,
+			whitelisted_buyer: Some(2)
+		}));
+
+		assert_noop!(
+			Uniques::mint_and_list(RuntimeOrigin::signed(1), 0, 42, 20, None),
+			Error::<Test>::AlreadyExists
+		);
+		assert_eq!(ItemPriceOf::<Test>::get(0, 42), Some((10, Some(2))));
+	});
+}
+
+#[test]
+fn metadata_update_cooldown_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { metadata_update_cooldown: Some(5), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![42], false, None));
+		System::set_block_number(5);
+		assert_noop!(
+			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![69], false, None),
+			Error::<Test>::MetadataUpdateTooSoon
+		);
+		System::set_block_number(6);
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![69], false, None));
+
+		// `ForceOrigin` is not rate-limited, but still restarts the cooldown.
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![7], false, None));
+		assert_noop!(
+			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![42], false, None),
+			Error::<Test>::MetadataUpdateTooSoon
+		);
+
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![42], false, None));
+	});
+}
+
+#[test]
+fn transfer_many_to_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 1, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 2, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 3, 2));
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(1), 0, 2));
+
+		assert_noop!(
+			Uniques::transfer_many_to(RuntimeOrigin::signed(2), 0, bvec![1, 2, 3], 3, false),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Uniques::transfer_many_to(RuntimeOrigin::signed(3), 0, bvec![1, 3], 3, false),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Uniques::transfer_many_to(RuntimeOrigin::signed(2), 0, bvec![1, 2, 3], 3, true));
+		assert_eq!(items(), vec![(2, 0, 2), (3, 0, 1), (3, 0, 3)]);
+		assert!(events().contains(&Event::<Test>::ItemsTransferred {
+			collection: 0,
+			items: bvec![1, 3],
+			dest: 3
+		}));
+	});
+}
+
+#[test]
+fn burn_on_transfer_to_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		let config = CollectionConfig {
+			burn_on_transfer_to: Some(5),
+			required_membership: Some(0),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(Balances::reserved_balance(&1), 3);
+
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 5),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 5));
+		assert_eq!(items(), vec![]);
+		assert_eq!(Balances::reserved_balance(&1), 2);
+		assert!(events().contains(&Event::<Test>::Burned {
+			collection: 0,
+			item: 42,
+			owner: 2,
+			burner: Some(2),
+			via_sink: true,
+		}));
+	});
+}
+
+#[test]
+fn item_attributes_adjust_the_reserved_deposit() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		// `AttributeDepositBase + DepositPerByte * (key.len + value.len)` for each attribute.
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![0]
+		));
+		assert_eq!(Balances::reserved_balance(&1), 4);
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![1],
+			bvec![0; 4]
+		));
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![1],
+			bvec![0]
+		));
+		assert_eq!(Balances::reserved_balance(&1), 7);
+
+		// refreshing only touches the deposit of the item itself.
+		assert_ok!(Uniques::refresh_item_deposit(RuntimeOrigin::signed(1), 0, 42));
+		assert_eq!(Balances::reserved_balance(&1), 7);
+
+		assert_ok!(Uniques::clear_attribute(RuntimeOrigin::signed(1), 0, Some(42), bvec![0]));
+		assert_eq!(Balances::reserved_balance(&1), 4);
+		assert_ok!(Uniques::clear_attribute(RuntimeOrigin::signed(1), 0, Some(42), bvec![1]));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+	});
+}
+
+#[test]
+fn id_parity_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { id_parity: IdParity::Even, ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_noop!(
+			Uniques::mint(RuntimeOrigin::signed(1), 0, 41, 2),
+			Error::<Test>::WrongIdParity
+		);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 0, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 256, 2));
+
+		let config = CollectionConfig { id_parity: IdParity::Odd, ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_noop!(
+			Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2),
+			Error::<Test>::WrongIdParity
+		);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 257, 2));
+		assert_eq!(items(), vec![(2, 0, 0), (2, 0, 256), (2, 0, 257)]);
+	});
+}
+
+#[test]
+fn collection_config_raw_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_eq!(Uniques::collection_config_raw(0), None);
+
+		let config = CollectionConfig { max_mints_per_block: Some(3), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		let raw = Uniques::collection_config_raw(0).unwrap();
+		assert_eq!(raw, config.encode());
+		assert_eq!(CollectionConfigFor::<Test>::decode(&mut &raw[..]).unwrap(), config);
+	});
+}
+
+#[test]
+fn collection_bond_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_noop!(
+			Uniques::set_collection_bond(RuntimeOrigin::signed(2), 0, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::release_collection_bond(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoBond
+		);
+
+		assert_ok!(Uniques::set_collection_bond(RuntimeOrigin::signed(1), 0, 10));
+		assert_ok!(Uniques::set_collection_bond(RuntimeOrigin::signed(1), 0, 20));
+		assert_eq!(Balances::reserved_balance(&1), 20);
+		assert!(events().contains(&Event::<Test>::CollectionBondSet { collection: 0, amount: 20 }));
+		assert_noop!(
+			Uniques::set_collection_bond(RuntimeOrigin::signed(1), 0, 5),
+			Error::<Test>::BondTooLow
+		);
+
+		System::set_block_number(6);
+		assert_noop!(
+			Uniques::slash_collection_bond(RuntimeOrigin::signed(1), 0, 5),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Uniques::slash_collection_bond(RuntimeOrigin::root(), 0, 5));
+		assert_eq!(Balances::reserved_balance(&1), 15);
+		assert_eq!(Balances::total_balance(&1), 95);
+		assert!(
+			events().contains(&Event::<Test>::CollectionBondSlashed { collection: 0, amount: 5 })
+		);
+
+		// the cooldown starts over with the slash.
+		System::set_block_number(15);
+		assert_noop!(
+			Uniques::release_collection_bond(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::BondLocked
+		);
+
+		// the bond moves along with the ownership.
+		assert_ok!(Uniques::set_accept_ownership(RuntimeOrigin::signed(2), Some(0)));
+		assert_ok!(Uniques::transfer_ownership(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 15);
+
+		System::set_block_number(16);
+		assert_ok!(Uniques::release_collection_bond(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert!(
+			events().contains(&Event::<Test>::CollectionBondReleased { collection: 0, amount: 15 })
+		);
+	});
+}
+
+#[test]
+fn transfer_as_delegate_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		// neither the owner nor the admin are enough without an approval.
+		assert_noop!(
+			Uniques::transfer_as_delegate(RuntimeOrigin::signed(2), 0, 42, 4),
+			Error::<Test>::NotApproved
+		);
+		assert_noop!(
+			Uniques::transfer_as_delegate(RuntimeOrigin::signed(1), 0, 42, 4),
+			Error::<Test>::NotApproved
+		);
+
+		System::set_block_number(1);
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, Some(2)));
+		System::set_block_number(4);
+		assert_noop!(
+			Uniques::transfer_as_delegate(RuntimeOrigin::signed(3), 0, 42, 4),
+			Error::<Test>::ApprovalExpired
+		);
+
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, None));
+		assert_ok!(Uniques::transfer_as_delegate(RuntimeOrigin::signed(3), 0, 42, 4));
+		assert_eq!(items(), vec![(4, 0, 42)]);
+		assert!(events().contains(&Event::<Test>::ApprovalConsumed {
+			collection: 0,
+			item: 42,
+			owner: 2,
+			delegate: 3,
+		}));
+
+		// the approval is used up.
+		assert_noop!(
+			Uniques::transfer_as_delegate(RuntimeOrigin::signed(3), 0, 42, 3),
+			Error::<Test>::NotApproved
+		);
+	});
+}
+
+#[test]
+fn ownership_counts_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		assert!(!Uniques::owns_any_in_collection(&2, 0));
+		assert_eq!(Uniques::count_in_collection(&2, 0), 0);
+
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 42, 2));
+		assert!(Uniques::owns_any_in_collection(&2, 0));
+		assert_eq!(Uniques::count_in_collection(&2, 0), 2);
+		assert_eq!(Uniques::count_in_collection(&2, 1), 1);
+
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 43, None));
+		assert!(!Uniques::owns_any_in_collection(&2, 0));
+		assert_eq!(Uniques::count_in_collection(&2, 0), 0);
+		assert_eq!(Uniques::count_in_collection(&3, 0), 1);
+	});
+}
+
+#[test]
+fn metadata_storage_proof_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(Balances::reserved_balance(&1), 3);
+		assert_eq!(Uniques::item_storage_proof(0, 42), None);
+
+		// the proof is paid for along with the data.
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![42, 42],
+			false,
+			Some(bvec![7, 7, 7])
+		));
+		assert_eq!(Balances::reserved_balance(&1), 9);
+		assert_eq!(Uniques::item_storage_proof(0, 42), Some(vec![7, 7, 7]));
+		assert!(events().contains(&Event::<Test>::MetadataSet {
+			collection: 0,
+			item: 42,
+			data: bvec![42, 42],
+			is_frozen: false,
+			storage_proof: Some(bvec![7, 7, 7]),
+		}));
+
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![42, 42],
+			false,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(&1), 6);
+		assert_eq!(Uniques::item_storage_proof(0, 42), None);
+	});
+}
+
+#[test]
+fn mint_cooldown_per_account_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config =
+			CollectionConfig { mint_cooldown_per_account: Some(100), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 1, 2));
+		System::set_block_number(100);
+		assert_noop!(Uniques::mint(RuntimeOrigin::signed(1), 0, 2, 2), Error::<Test>::MintCooldown);
+
+		// other minters have a cooldown of their own.
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(1), 0, 3, 1, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(3), 0, 2, 2));
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(1), 0, 1, 1, 1));
+
+		System::set_block_number(101);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 3, 2));
+		assert_eq!(LastMintByAccountOf::<Test>::get(0, 1), Some(101));
+		assert_noop!(Uniques::mint(RuntimeOrigin::signed(1), 0, 4, 2), Error::<Test>::MintCooldown);
+	});
+}
+
+#[test]
+fn mint_receipts_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert!(!events().iter().any(|e| matches!(e, Event::<Test>::MintReceipt { .. })));
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::MintReceipts.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+		let events = events();
+		assert!(events.contains(&Event::<Test>::Issued { collection: 0, item: 43, owner: 2 }));
+		assert!(events.contains(&Event::<Test>::MintReceipt {
+			collection: 0,
+			item: 43,
+			minter: Some(1),
+			price_paid: 0,
+			deposit_reserved: 1,
+			beneficiary: 2,
+		}));
+
+		// minting through the `nonfungibles` traits has no minter.
+		assert_ok!(<Uniques as Mutate<_>>::mint_into(&0, &44, &3));
+		assert!(events().contains(&Event::<Test>::MintReceipt {
+			collection: 0,
+			item: 44,
+			minter: None,
+			price_paid: 0,
+			deposit_reserved: 1,
+			beneficiary: 3,
+		}));
+	});
+}
+
+#[test]
+fn is_approved_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert!(!Uniques::is_approved(0, 42, &3));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert!(!Uniques::is_approved(0, 42, &3));
+
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 3, Some(2)));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, 42, 4, None));
+		System::set_block_number(3);
+		assert!(Uniques::is_approved(0, 42, &3));
+		assert!(Uniques::is_approved(0, 42, &4));
+
+		// the expired approval is still stored, but no longer valid.
+		System::set_block_number(4);
+		assert!(Item::<Test>::get(0, 42).unwrap().approvals.contains_key(&3));
+		assert!(!Uniques::is_approved(0, 42, &3));
+		assert!(Uniques::is_approved(0, 42, &4));
+
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 5));
+		assert!(!Uniques::is_approved(0, 42, &4));
+	});
+}
+
+#[test]
+fn metadata_mutable_until_age_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		let config =
+			CollectionConfig { metadata_mutable_until_age: Some(10), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(ItemMintedAt::<Test>::get(0, 42), Some(1));
+
+		System::set_block_number(11);
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![42], false, None));
+
+		System::set_block_number(12);
+		assert_noop!(
+			Uniques::set_metadata(RuntimeOrigin::signed(1), 0, 42, bvec![69], false, None),
+			Error::<Test>::MetadataWindowClosed
+		);
+		// `ForceOrigin` isn't bound by the window.
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![69], false, None));
+
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(1), 0, 42, None));
+		assert_eq!(ItemMintedAt::<Test>::get(0, 42), None);
+	});
+}
+
+#[test]
+fn burn_requirement_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		let requirement = BurnRequirement { key: bvec![1], value: bvec![0] };
+		assert_noop!(
+			Uniques::set_burn_requirement(RuntimeOrigin::signed(2), 0, Some(requirement.clone())),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_burn_requirement(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(requirement.clone())
+		));
+		assert!(events().contains(&Event::<Test>::BurnRequirementSet {
+			collection: 0,
+			requirement: Some(requirement),
+		}));
+
+		// neither a missing nor a different value meets the requirement.
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None),
+			Error::<Test>::BurnConditionNotMet
+		);
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![1],
+			bvec![3]
+		));
+		assert_noop!(
+			Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None),
+			Error::<Test>::BurnConditionNotMet
+		);
+
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![1],
+			bvec![0]
+		));
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_eq!(items(), vec![]);
+
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+		assert_ok!(Uniques::set_burn_requirement(RuntimeOrigin::root(), 0, None));
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 43, None));
+	});
+}
+
+#[test]
+fn max_distinct_owners_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { max_distinct_owners: Some(2), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 1, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 2, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 3, 3));
+		assert_eq!(Uniques::distinct_owners(0), 2);
+
+		// existing owners may still receive items, new ones may not.
+		assert_noop!(
+			Uniques::mint(RuntimeOrigin::signed(1), 0, 4, 4),
+			Error::<Test>::TooManyOwners
+		);
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 1, 4),
+			Error::<Test>::TooManyOwners
+		);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 4, 3));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 1, 3));
+		assert_eq!(Uniques::distinct_owners(0), 2);
+
+		// handing over the last item makes room for the receiver.
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 2, 4));
+		assert_eq!(Uniques::distinct_owners(0), 2);
+
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(4), 0, 2, None));
+		assert_eq!(Uniques::distinct_owners(0), 1);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 5, 5));
+		assert_eq!(Uniques::distinct_owners(0), 2);
+	});
+}
+
+#[test]
+fn stamp_mint_provenance_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::StampMintProvenance.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		System::set_block_number(7);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(42), MINTED_AT_ATTRIBUTE.to_vec(), 7u64.encode()),
+				(Some(42), MINTED_BY_ATTRIBUTE.to_vec(), 1u64.encode()),
+			]
+		);
+		assert_eq!(
+			<Uniques as Inspect<_>>::attribute(&0, &42, MINTED_BY_ATTRIBUTE),
+			Some(1u64.encode())
+		);
+		// the collection deposit, the item deposit and two attribute deposits of 1 + 9 + 8 each.
+		assert_eq!(Balances::reserved_balance(&1), 2 + 1 + 18 + 18);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 2);
+
+		// mints through the `nonfungibles` traits are stamped with the owner of the collection.
+		assert_ok!(<Uniques as Mutate<_>>::mint_into(&0, &43, &3));
+		assert_eq!(
+			<Uniques as Inspect<_>>::attribute(&0, &43, MINTED_BY_ATTRIBUTE),
+			Some(1u64.encode())
+		);
+	});
+}
+
+#[test]
+fn holding_period_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { holding_period: Some(5), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		// the period starts with the mint.
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3),
+			Error::<Test>::HoldingPeriodActive
+		);
+		System::set_block_number(5);
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3),
+			Error::<Test>::HoldingPeriodActive
+		);
+		System::set_block_number(6);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_eq!(LastTransferOf::<Test>::get(0, 42), Some(6));
+
+		// and starts over with every transfer.
+		System::set_block_number(10);
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 4),
+			Error::<Test>::HoldingPeriodActive
+		);
+		System::set_block_number(11);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
+		assert_eq!(items(), vec![(4, 0, 42)]);
+	});
+}
+
+#[test]
+fn clear_all_collection_approvals_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		for item in 42..45 {
+			assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, item, 2));
+			assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(2), 0, item, 3, None));
+		}
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 45, 2));
+
+		assert_noop!(
+			Uniques::clear_all_collection_approvals(RuntimeOrigin::signed(2), 0, 4, None),
+			Error::<Test>::NoPermission
+		);
+
+		// the items are visited in storage order, so which get cleared first is unknown.
+		assert_ok!(Uniques::clear_all_collection_approvals(RuntimeOrigin::signed(1), 0, 2, None));
+		let resume_from = match events().last() {
+			Some(Event::AllApprovalsCleared { collection: 0, resume_from: Some(item), .. }) =>
+				*item,
+			_ => panic!("expected the clearing to be incomplete"),
+		};
+		let approved = (42..46)
+			.filter(|item| !Item::<Test>::get(0, item).unwrap().approvals.is_empty())
+			.count();
+		assert!(approved >= 1);
+
+		assert_ok!(Uniques::clear_all_collection_approvals(
+			RuntimeOrigin::root(),
+			0,
+			2,
+			Some(resume_from)
+		));
+		assert!(events().contains(&Event::AllApprovalsCleared {
+			collection: 0,
+			cleared: approved as u32,
+			resume_from: None,
+		}));
+		assert!((42..46).all(|item| Item::<Test>::get(0, item).unwrap().approvals.is_empty()));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 3),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn recover_item_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(1), 0, 42));
+		assert_noop!(
+			Uniques::recover_item(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::NoPermission
+		);
+
+		// once the collection has items, only `ForceOrigin` may name a recovery account.
+		let config = CollectionConfig { recovery_account: Some(3), ..Default::default() };
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::CollectionNotEmpty
+		);
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::root(), 0, config));
+		assert_eq!(Uniques::recovery_account(0), Some(3));
+		assert!(Collection::<Test>::get(0).unwrap().has_recovery_account);
+		assert_noop!(
+			Uniques::recover_item(RuntimeOrigin::signed(1), 0, 42),
+			Error::<Test>::NoPermission
+		);
+
+		// the item is reclaimed even though it is frozen.
+		assert_ok!(Uniques::recover_item(RuntimeOrigin::signed(3), 0, 42));
+		assert_eq!(items(), vec![(3, 0, 42)]);
+		let events = events();
+		assert!(events.contains(&Event::<Test>::Transferred {
+			collection: 0,
+			item: 42,
+			from: 2,
+			to: 3
+		}));
+		assert!(events.contains(&Event::<Test>::ItemRecovered { collection: 0, item: 42 }));
+	});
+}
+
+#[test]
+fn listing_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 10);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { listing_fee: Some(3), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 3));
+
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(2), 0, 42, Some(20), None));
+		assert_eq!(Balances::free_balance(&2), 7);
+		assert!(events().contains(&Event::<Test>::ListingFeePaid {
+			collection: 0,
+			item: 42,
+			lister: 2,
+			fee: 3
+		}));
+
+		// changing the price of a listing is free, listing the item again is not.
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(2), 0, 42, Some(30), None));
+		assert_eq!(Balances::free_balance(&2), 7);
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(2), 0, 42, None, None));
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(2), 0, 42, Some(30), None));
+		assert_eq!(Balances::free_balance(&2), 4);
+
+		// the item isn't listed if its owner can't pay the fee.
+		assert_noop!(
+			Uniques::set_price(RuntimeOrigin::signed(3), 0, 43, Some(20), None),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+		assert!(ItemPriceOf::<Test>::get(0, 43).is_none());
+	});
+}
+
+#[test]
+fn approve_unlock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(1), 0, 2, 3, 4));
+		let config = CollectionConfig { unlock_threshold: 5, ..Default::default() };
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::InvalidUnlockThreshold
+		);
+		let config = CollectionConfig { unlock_threshold: 2, ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(2), 0, 42, 5));
+		assert_noop!(
+			Uniques::approve_unlock(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::NotFrozen
+		);
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(4), 0, 42));
+
+		// the admin can't thaw alone, and only role holders may approve.
+		assert_noop!(
+			Uniques::thaw(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::UnlockNotApproved
+		);
+		assert_noop!(
+			Uniques::approve_unlock(RuntimeOrigin::signed(5), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::approve_unlock(RuntimeOrigin::signed(3), 0, 42));
+		assert!(events().contains(&Event::<Test>::UnlockApprovalAdded {
+			collection: 0,
+			item: 42,
+			approver: 3,
+			approvals: 1
+		}));
+		assert_noop!(
+			Uniques::approve_unlock(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::UnlockAlreadyApproved
+		);
+		assert!(Item::<Test>::get(0, 42).unwrap().is_frozen);
+
+		// freezing again starts over.
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(4), 0, 42));
+		assert!(UnlockApprovalsOf::<Test>::get(0, 42).is_empty());
+		assert_ok!(Uniques::approve_unlock(RuntimeOrigin::signed(3), 0, 42));
+		assert_ok!(Uniques::approve_unlock(RuntimeOrigin::signed(4), 0, 42));
+		assert!(!Item::<Test>::get(0, 42).unwrap().is_frozen);
+		assert!(UnlockApprovalsOf::<Test>::get(0, 42).is_empty());
+		let events = events();
+		assert!(events.contains(&Event::<Test>::Thawed { collection: 0, item: 42 }));
+		assert!(events.contains(&Event::<Test>::ItemTransferUnlocked { collection: 0, item: 42 }));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(5), 0, 42, 6));
+	});
+}
+
+#[test]
+fn muted_events_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig {
+			muted_events: EventMask(EventCategory::Transfers | EventCategory::Attributes),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![0]
+		));
+		assert_ok!(Uniques::set_attribute(RuntimeOrigin::signed(1), 0, None, bvec![0], bvec![0]));
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(3), 0, 42, Some(1), None));
+
+		// mints, collection attributes and the categories which aren't muted are still deposited.
+		let events = events();
+		assert!(events.contains(&Event::<Test>::Issued { collection: 0, item: 42, owner: 2 }));
+		assert!(events.contains(&Event::<Test>::AttributeSet {
+			collection: 0,
+			maybe_item: None,
+			key: bvec![0],
+			value: bvec![0]
+		}));
+		assert!(events.contains(&Event::<Test>::ItemPriceSet {
+			collection: 0,
+			item: 42,
+			price: 1,
+			whitelisted_buyer: None
+		}));
+		assert!(!events.iter().any(|event| matches!(
+			event,
+			Event::<Test>::Transferred { .. } |
+				Event::<Test>::AttributeSet { maybe_item: Some(_), .. }
+		)));
+		assert_eq!(items(), vec![(3, 0, 42)]);
+	});
+}
+
+#[test]
+fn item_minted_at_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		System::set_block_number(3);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(Uniques::item_minted_at(0, 42), Some(3));
+		assert_eq!(Uniques::item_minted_at(0, 43), None);
+
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_eq!(Uniques::item_minted_at(0, 42), None);
+	});
+}
+
+#[test]
+fn escrow_should_not_restart_holding_period() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { holding_period: Some(5), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		assert_noop!(
+			Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3),
+			Error::<Test>::HoldingPeriodActive
+		);
+		System::set_block_number(6);
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3));
+		// taking the item into custody is not a transfer.
+		assert_eq!(LastTransferOf::<Test>::get(0, 42), None);
+		assert_eq!(TransferCountOf::<Test>::get(0, 42), 0);
+
+		assert_ok!(Uniques::fund_escrow(RuntimeOrigin::signed(3), 0, 42));
+		assert_ok!(Uniques::confirm_escrow(RuntimeOrigin::signed(2), 0, 42));
+		assert_ok!(Uniques::confirm_escrow(RuntimeOrigin::signed(3), 0, 42));
+		assert_eq!(items(), vec![(3, 0, 42)]);
+		assert_eq!(LastTransferOf::<Test>::get(0, 42), Some(6));
+		assert_eq!(TransferCountOf::<Test>::get(0, 42), 1);
+	});
+}
+
+#[test]
+fn escrow_account_should_not_take_an_owner_slot() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&4, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { max_distinct_owners: Some(2), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 1, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 2, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 3, 3));
+
+		// the seller keeps another item, so the buyer would be a third owner.
+		assert_noop!(
+			Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 1, 30, 4),
+			Error::<Test>::TooManyOwners
+		);
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(3), 0, 3, 30, 4));
+		assert_eq!(Uniques::distinct_owners(0), 1);
+
+		assert_ok!(Uniques::fund_escrow(RuntimeOrigin::signed(4), 0, 3));
+		assert_ok!(Uniques::confirm_escrow(RuntimeOrigin::signed(3), 0, 3));
+		assert_ok!(Uniques::confirm_escrow(RuntimeOrigin::signed(4), 0, 3));
+		assert_eq!(items(), vec![(2, 0, 1), (2, 0, 2), (4, 0, 3)]);
+		assert_eq!(Uniques::distinct_owners(0), 2);
+	});
+}
+
+#[test]
+fn escrow_should_check_the_buyer() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::RequireFirstTransferApproval.into()),
+			required_membership: Some(1),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 7, 3));
+
+		assert_noop!(
+			Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 4),
+			Error::<Test>::DestinationNotMember
+		);
+		assert_noop!(
+			Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3),
+			Error::<Test>::FirstTransferNotApproved
+		);
+		assert_ok!(Uniques::approve_first_transfer(RuntimeOrigin::signed(1), 0, 42));
+		assert_ok!(Uniques::open_escrow(RuntimeOrigin::signed(2), 0, 42, 30, 3));
+
+		assert_ok!(Uniques::fund_escrow(RuntimeOrigin::signed(3), 0, 42));
+		assert_ok!(Uniques::confirm_escrow(RuntimeOrigin::signed(2), 0, 42));
+		assert_ok!(Uniques::confirm_escrow(RuntimeOrigin::signed(3), 0, 42));
+		assert_eq!(items(), vec![(3, 0, 42), (3, 1, 7)]);
+	});
+}
+
+#[test]
+fn sequential_ids_should_not_be_taken_by_explicit_mints() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 0, 2));
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::SequentialIds.into()),
+			..Default::default()
+		};
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::CollectionNotEmpty
+		);
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 0, None));
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		assert_noop!(
+			Uniques::mint(RuntimeOrigin::signed(1), 0, 0, 2),
+			Error::<Test>::SequentialIdsEnabled
+		);
+		assert_noop!(
+			<Uniques as Mutate<_>>::mint_into(&0, &1, &2),
+			Error::<Test>::SequentialIdsEnabled
+		);
+		assert_eq!(Uniques::next_item_id(0), Some(0));
+		assert_ok!(Uniques::mint_next(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Uniques::mint_next(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(items(), vec![(2, 0, 0), (2, 0, 1)]);
+		assert_eq!(Uniques::next_item_id(0), Some(2));
+	});
+}
+
+#[test]
+fn check_deposits_should_catch_a_surplus() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(CollectionCreationDepositOf::<Test>::get(0), 2);
+		assert_ok!(Uniques::check_deposits(0));
+
+		// even if the owner has it reserved, a total beyond the recorded deposits leaks.
+		assert_ok!(Balances::reserve(&1, 1));
+		Collection::<Test>::mutate(0, |details| details.as_mut().unwrap().total_deposit += 1);
+		assert_noop!(Uniques::check_deposits(0), Error::<Test>::DepositMismatch);
+
+		Collection::<Test>::mutate(0, |details| details.as_mut().unwrap().total_deposit -= 1);
+		let w = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Uniques::destroy(RuntimeOrigin::signed(1), 0, w));
+		assert!(!CollectionCreationDepositOf::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn migrate_to_latest_should_translate_old_layouts() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 3));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			42,
+			bvec![0u8; 10],
+			true,
+			None
+		));
+		let item_deposit = Item::<Test>::get(0, 42).unwrap().deposit;
+		let metadata_deposit = ItemMetadataOf::<Test>::get(0, 42).unwrap().deposit;
+
+		// roll the storage back to the layouts of v0, with a single approved account per item.
+		unhashed::put_raw(
+			&Item::<Test>::hashed_key_for(0, 42),
+			&(2u64, Some(4u64), false, item_deposit).encode(),
+		);
+		unhashed::put_raw(
+			&ItemMetadataOf::<Test>::hashed_key_for(0, 42),
+			&(metadata_deposit, vec![0u8; 10], true).encode(),
+		);
+		unhashed::put_raw(
+			&Item::<Test>::hashed_key_for(0, 43),
+			&(3u64, None::<u64>, true, item_deposit).encode(),
+		);
+		let details = Collection::<Test>::get(0).unwrap();
+		unhashed::put_raw(
+			&Collection::<Test>::hashed_key_for(0),
+			&(
+				details.owner,
+				details.issuer,
+				details.admin,
+				details.freezer,
+				details.total_deposit,
+				details.free_holding,
+				details.items,
+				details.item_metadatas,
+				details.attributes,
+				details.is_frozen,
+			)
+				.encode(),
+		);
+		CollectionAccount::<Test>::remove(1, 0);
+		OwnedCollectionsCountOf::<Test>::remove(1);
+		DistinctOwnersCountOf::<Test>::remove(0);
+		CollectionCreationDepositOf::<Test>::remove(0);
+		StorageVersion::new(0).put::<Uniques>();
+
+		migration::MigrateToLatest::<Test>::on_runtime_upgrade();
+		assert_eq!(Uniques::on_chain_storage_version(), 6);
+		assert_eq!(Collection::<Test>::get(0), Some(details));
+
+		let details = Item::<Test>::get(0, 42).unwrap();
+		assert_eq!(details.owner, 2);
+		assert_eq!(details.approvals.into_iter().collect::<Vec<_>>(), vec![(4, None)]);
+		assert!(!details.is_frozen);
+		assert_eq!(details.deposit, item_deposit);
+		let details = Item::<Test>::get(0, 43).unwrap();
+		assert_eq!(details.owner, 3);
+		assert!(details.approvals.is_empty());
+		assert!(details.is_frozen);
+
+		let metadata = ItemMetadataOf::<Test>::get(0, 42).unwrap();
+		assert_eq!(metadata.deposit, metadata_deposit);
+		assert_eq!(metadata.data, vec![0u8; 10]);
+		assert!(metadata.is_frozen);
+		assert_eq!(metadata.storage_proof, None);
+
+		assert!(CollectionAccount::<Test>::contains_key(1, 0));
+		assert_eq!(OwnedCollectionsCountOf::<Test>::get(1), 1);
+		assert_eq!(Uniques::distinct_owners(0), 2);
+		assert_eq!(CollectionCreationDepositOf::<Test>::get(0), 2);
+		assert_ok!(Uniques::do_try_state());
+
+		// the migrations only apply once.
+		migration::MigrateToLatest::<Test>::on_runtime_upgrade();
+		assert_eq!(Uniques::distinct_owners(0), 2);
+		assert_eq!(OwnedCollectionsCountOf::<Test>::get(1), 1);
+	});
+}
+
+#[test]
+fn revealed_should_only_be_set_by_reveal_collection() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, false));
+		let hash = <Test as frame_system::Config>::Hashing::hash(&[1u8, 2, 3]);
+		assert_ok!(Uniques::commit_reveal_hash(RuntimeOrigin::signed(1), 0, hash));
+		assert_ok!(Uniques::set_unrevealed_metadata(RuntimeOrigin::signed(1), 0, bvec![0]));
+
+		// revealing through the configuration would skip the commitment.
+		let revealed = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::Revealed.into()),
+			..Default::default()
+		};
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, revealed),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::root(), 0, revealed),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Uniques::reveal_collection(RuntimeOrigin::signed(1), 0, Some(bvec![1, 2, 3])));
+		assert!(!UnrevealedMetadataOf::<Test>::contains_key(0));
+
+		// and a revealed collection can't be hidden again.
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, Default::default()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, revealed));
+		assert_noop!(
+			Uniques::set_unrevealed_metadata(RuntimeOrigin::signed(1), 0, bvec![0]),
+			Error::<Test>::AlreadyRevealed
+		);
+	});
+}
+
+#[test]
+fn recovery_account_should_be_disclosed() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert!(!Collection::<Test>::get(0).unwrap().has_recovery_account);
+
+		// the owner may name a recovery account before the first mint.
+		let config = CollectionConfig { recovery_account: Some(3), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert!(Collection::<Test>::get(0).unwrap().has_recovery_account);
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		// but can't change it afterwards, only drop it.
+		let changed = CollectionConfig { recovery_account: Some(4), ..Default::default() };
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, changed),
+			Error::<Test>::CollectionNotEmpty
+		);
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, Default::default()));
+		assert!(!Collection::<Test>::get(0).unwrap().has_recovery_account);
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::CollectionNotEmpty
+		);
+
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::root(), 0, changed));
+		assert!(Collection::<Test>::get(0).unwrap().has_recovery_account);
+		assert_eq!(Uniques::recovery_account(0), Some(4));
+	});
+}
+
+#[test]
+fn approve_unlock_should_need_a_threshold() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(1), 0, 2, 3, 4));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(2), 0, 42, 5));
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(4), 0, 42));
+
+		// by default only the admin may thaw, the other role holders can't do so by approving.
+		for who in [1, 2, 3, 4] {
+			assert_noop!(
+				Uniques::approve_unlock(RuntimeOrigin::signed(who), 0, 42),
+				Error::<Test>::NoUnlockThreshold
+			);
+		}
+		let config = CollectionConfig { unlock_threshold: 1, ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::root(), 0, config));
+		assert_noop!(
+			Uniques::approve_unlock(RuntimeOrigin::signed(4), 0, 42),
+			Error::<Test>::NoUnlockThreshold
+		);
+		assert!(Item::<Test>::get(0, 42).unwrap().is_frozen);
+
+		// once there are items, the owner can't change the threshold.
+		let config = CollectionConfig { unlock_threshold: 2, ..Default::default() };
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config),
+			Error::<Test>::CollectionNotEmpty
+		);
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::root(), 0, config));
+		assert_noop!(
+			Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, Default::default()),
+			Error::<Test>::CollectionNotEmpty
+		);
+		assert_noop!(
+			Uniques::thaw(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::UnlockNotApproved
+		);
+	});
+}
+
+#[test]
+fn clear_collection_metadata_should_keep_deposits_consistent() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Uniques::set_collection_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			bvec![0u8; 20],
+			false
+		));
+		assert_eq!(Collection::<Test>::get(0).unwrap().total_deposit, 2 + 21);
+		assert_ok!(Uniques::check_deposits(0));
+
+		assert_ok!(Uniques::clear_collection_metadata(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Collection::<Test>::get(0).unwrap().total_deposit, 2);
+		assert_eq!(Balances::reserved_balance(&1), 2);
+		assert_ok!(Uniques::check_deposits(0));
+	});
+}
+
+#[test]
+fn mint_next_should_skip_ids_of_the_wrong_parity() {
+	new_test_ext().execute_with(|| {
+		for (collection, id_parity, minted) in
+			[(0, IdParity::Even, [0, 2, 4]), (1, IdParity::Odd, [1, 3, 5])]
+		{
+			assert_ok!(Uniques::force_create(RuntimeOrigin::root(), collection, 1, true));
+			let config = CollectionConfig {
+				settings: CollectionSettings(CollectionSetting::SequentialIds.into()),
+				id_parity,
+				..Default::default()
+			};
+			assert_ok!(Uniques::set_collection_config(
+				RuntimeOrigin::signed(1),
+				collection,
+				config
+			));
+
+			for item in minted {
+				assert_eq!(Uniques::next_item_id(collection), Some(item));
+				assert_ok!(Uniques::mint_next(RuntimeOrigin::signed(1), collection, 2));
+				assert!(events().contains(&Event::<Test>::Issued { collection, item, owner: 2 }));
+			}
+			assert_eq!(Uniques::count_in_collection(&2, collection), 3);
+		}
+	});
+}
+
+#[test]
+fn wrap_item_should_only_take_custody() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		let config = CollectionConfig {
+			holding_period: Some(10),
+			max_distinct_owners: Some(1),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 1, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 69, 2));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 1, 69, 3),
+			Error::<Test>::HoldingPeriodActive
+		);
+
+		// neither the holding period nor the owner limit apply to the wrap account.
+		assert_ok!(Uniques::wrap_item(RuntimeOrigin::signed(2), (0, 42), (1, 69)));
+		assert_eq!(Uniques::distinct_owners(1), 0);
+		assert_ok!(Uniques::unwrap_item(RuntimeOrigin::signed(2), (1, 69)));
+		assert_eq!(Uniques::owner(1, 69), Some(2));
+		assert_eq!(Uniques::distinct_owners(1), 1);
+
+		// nor is either of them counted as a transfer.
+		assert_eq!(TransferCountOf::<Test>::get(1, 69), 0);
+		assert!(!LastTransferOf::<Test>::contains_key(1, 69));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 1, 69, 3),
+			Error::<Test>::HoldingPeriodActive
+		);
+	});
+}
+
+#[test]
+fn transfer_policy_should_veto_burning_through_the_sink() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		let config = CollectionConfig { burn_on_transfer_to: Some(5), ..Default::default() };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+
+		SanctionedAccount::set(Some(2));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 5),
+			DispatchError::Other("sanctioned")
+		);
+		assert_eq!(Uniques::owner(0, 42), Some(2));
+
+		SanctionedAccount::set(None);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 5));
+		assert_eq!(Uniques::owner(0, 42), None);
+	});
+}
+
+#[test]
+fn unique_item_names_should_only_switch_without_names() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		let unique = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::UniqueItemNames.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, unique));
+		assert_ok!(Uniques::set_item_name(RuntimeOrigin::signed(1), 0, 42, bvec![0]));
+		assert_eq!(Uniques::item_by_name(0, vec![0]), Some(42));
+
+		// switching it off would leave the index behind, for the owner and `ForceOrigin` alike.
+		for origin in [RuntimeOrigin::signed(1), RuntimeOrigin::root()] {
+			assert_noop!(
+				Uniques::set_collection_config(origin, 0, Default::default()),
+				Error::<Test>::ItemsNamed
+			);
+		}
+
+		// other changes are fine, and once the names are gone the setting may be switched.
+		let config = CollectionConfig { holding_period: Some(1), ..unique };
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_eq!(Uniques::item_by_name(0, vec![0]), None);
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, Default::default()));
+	});
+}
+
+#[test]
+fn first_transfer_approval_should_not_lock_transferred_items() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 69, 2));
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(2), 0, 42, 3));
+
+		let config = CollectionConfig {
+			settings: CollectionSettings(CollectionSetting::RequireFirstTransferApproval.into()),
+			..Default::default()
+		};
+		assert_ok!(Uniques::set_collection_config(RuntimeOrigin::signed(1), 0, config));
+
+		// the item changed hands before, so its first transfer is behind it.
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(3), 0, 42, 4));
+		assert_noop!(
+			Uniques::transfer(RuntimeOrigin::signed(2), 0, 69, 4),
+			Error::<Test>::FirstTransferNotApproved
+		);
+	});
+}
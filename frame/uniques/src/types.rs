@@ -18,20 +18,61 @@
 //! Various basic types for use in the Uniques pallet.
 
 use super::*;
+use enumflags2::{bitflags, BitFlags};
 use frame_support::{
 	pallet_prelude::{BoundedVec, MaxEncodedLen},
-	traits::Get,
+	traits::{ConstU32, Get},
+	BoundedBTreeMap, BoundedBTreeSet,
 };
-use scale_info::TypeInfo;
+use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
+
+/// The key of the attribute holding the SCALE encoded block an item was minted in, for
+/// collections with `StampMintProvenance` switched on.
+pub const MINTED_AT_ATTRIBUTE: &[u8] = b"minted_at";
+/// The key of the attribute holding the SCALE encoded account which minted an item, for
+/// collections with `StampMintProvenance` switched on.
+pub const MINTED_BY_ATTRIBUTE: &[u8] = b"minted_by";
 
 pub(super) type DepositBalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 pub(super) type CollectionDetailsFor<T, I> =
 	CollectionDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
+pub(super) type ApprovalsOf<T, I = ()> = BoundedBTreeMap<
+	<T as SystemConfig>::AccountId,
+	Option<<T as SystemConfig>::BlockNumber>,
+	<T as Config<I>>::MaxApprovalsPerItem,
+>;
 pub(super) type ItemDetailsFor<T, I> =
-	ItemDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
+	ItemDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>, ApprovalsOf<T, I>>;
+pub(super) type CollectionConfigFor<T, I = ()> = CollectionConfig<
+	DepositBalanceOf<T, I>,
+	<T as Config<I>>::ItemId,
+	<T as Config<I>>::CollectionId,
+	<T as SystemConfig>::BlockNumber,
+	<T as SystemConfig>::AccountId,
+>;
+pub(super) type NegativeImbalanceOf<T, I = ()> =
+	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::NegativeImbalance;
+pub(super) type CollectionBondFor<T, I = ()> =
+	CollectionBond<DepositBalanceOf<T, I>, <T as SystemConfig>::BlockNumber>;
+/// The most role holders a collection may have, and so the highest `unlock_threshold`.
+pub const MAX_UNLOCK_APPROVERS: u32 = 4;
+pub(super) type UnlockApprovalsFor<T> =
+	BoundedBTreeSet<<T as SystemConfig>::AccountId, ConstU32<MAX_UNLOCK_APPROVERS>>;
+pub(super) type MarketplacePolicyFor<T, I = ()> = MarketplacePolicy<
+	BoundedBTreeSet<<T as SystemConfig>::AccountId, <T as Config<I>>::MaxMarketplaces>,
+>;
+pub(super) type BurnRequirementFor<T, I = ()> = BurnRequirement<
+	BoundedVec<u8, <T as Config<I>>::KeyLimit>,
+	BoundedVec<u8, <T as Config<I>>::ValueLimit>,
+>;
 pub(super) type ItemPrice<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+pub(super) type EscrowDetailsFor<T, I> = EscrowDetails<
+	<T as SystemConfig>::AccountId,
+	ItemPrice<T, I>,
+	<T as SystemConfig>::BlockNumber,
+>;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct CollectionDetails<AccountId, DepositBalance> {
@@ -56,6 +97,9 @@ pub struct CollectionDetails<AccountId, DepositBalance> {
 	pub(super) attributes: u32,
 	/// Whether the collection is frozen for non-admin transfers.
 	pub(super) is_frozen: bool,
+	/// Whether the configuration of the collection names a `recovery_account`, which may reclaim
+	/// any of its items.
+	pub(super) has_recovery_account: bool,
 }
 
 /// Witness data for the destroy transactions.
@@ -84,11 +128,12 @@ impl<AccountId, DepositBalance> CollectionDetails<AccountId, DepositBalance> {
 
 /// Information concerning the ownership of a single unique item.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
-pub struct ItemDetails<AccountId, DepositBalance> {
+pub struct ItemDetails<AccountId, DepositBalance, Approvals> {
 	/// The owner of this item.
 	pub(super) owner: AccountId,
-	/// The approved transferrer of this item, if one is set.
-	pub(super) approved: Option<AccountId>,
+	/// The approved transferrers of this item, each with the block after which its approval
+	/// expires, if any.
+	pub(super) approvals: Approvals,
 	/// Whether the item can be transferred or not.
 	pub(super) is_frozen: bool,
 	/// The amount held in the pallet's default account for this item. Free-hold items will have
@@ -126,4 +171,407 @@ pub struct ItemMetadata<DepositBalance, StringLimit: Get<u32>> {
 	pub(super) data: BoundedVec<u8, StringLimit>,
 	/// Whether the item metadata may be changed by a non Force origin.
 	pub(super) is_frozen: bool,
+	/// The proof of a storage deal pinning `data` with some storage provider, if any. This is not
+	/// verified on-chain.
+	pub(super) storage_proof: Option<BoundedVec<u8, StringLimit>>,
+}
+
+/// A role which may be assigned to an account within a collection.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CollectionRole {
+	/// Can mint items.
+	Issuer,
+	/// Can thaw items, force transfers and burn items from any account.
+	Admin,
+	/// Can freeze items.
+	Freezer,
+}
+
+/// A freeze of a collection which recurs every `period` blocks.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RecurringLock<BlockNumber> {
+	/// The number of blocks the collection stays frozen each time.
+	pub(super) duration: BlockNumber,
+	/// The number of blocks between the start of two frozen windows.
+	pub(super) period: BlockNumber,
+	/// The block at which the collection is next thawed or frozen.
+	pub(super) next: BlockNumber,
+	/// Whether the collection is within a frozen window.
+	pub(super) frozen: bool,
+}
+
+/// An escrowed sale of a single item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EscrowDetails<AccountId, Balance, BlockNumber> {
+	/// The account which put the item into escrow and receives the price.
+	pub(super) seller: AccountId,
+	/// The only account which may fund the escrow and receive the item.
+	pub(super) buyer: AccountId,
+	/// The amount the buyer pays for the item.
+	pub(super) price: Balance,
+	/// Whether the buyer has reserved the `price`.
+	pub(super) funded: bool,
+	/// Whether the seller has confirmed the sale.
+	pub(super) seller_confirmed: bool,
+	/// Whether the buyer has confirmed the sale.
+	pub(super) buyer_confirmed: bool,
+	/// The block at which the escrow expires unless settled.
+	pub(super) expiry: BlockNumber,
+}
+
+/// A type whose values may be allocated in sequence.
+pub trait Incrementable: Sized {
+	/// The first value of the sequence.
+	fn initial_value() -> Self;
+	/// The value following `self`, or `None` if the sequence is exhausted.
+	fn increment(&self) -> Option<Self>;
+	/// Whether `self` is odd, i.e. at an odd position of the sequence starting from
+	/// `initial_value`. This is what the `IdParity` of a collection refers to.
+	fn is_odd(&self) -> bool;
+}
+
+macro_rules! impl_incrementable {
+	($($type:ty),+) => {
+		$(
+			impl Incrementable for $type {
+				fn initial_value() -> Self {
+					0
+				}
+				fn increment(&self) -> Option<Self> {
+					self.checked_add(1)
+				}
+				fn is_odd(&self) -> bool {
+					self % 2 == 1
+				}
+			}
+		)+
+	};
+}
+
+impl_incrementable!(u8, u16, u32, u64, u128);
+
+/// The state of all ongoing trades of a single item.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ItemTradingState<AccountId, Balance> {
+	/// The price the item is listed for, along with the only account allowed to buy it, if any.
+	pub listing: Option<(Balance, Option<AccountId>)>,
+	/// The buyer and the price of the escrowed sale of the item, if there is one.
+	pub escrow: Option<(AccountId, Balance)>,
+}
+
+/// Settings that may be switched on for a collection. All settings are disabled by default.
+#[bitflags]
+#[repr(u64)]
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum CollectionSetting {
+	/// Items of the collection may not be transferred to the account already owning them.
+	ForbidSelfTransfer = 0b0000000000000000000000000000000000000000000000000000000000000001,
+	/// Setting attributes doesn't reserve a deposit. The storage cost is implicitly borne by
+	/// the platform sponsoring the collection. Only `ForceOrigin` may change this setting.
+	FreeAttributes = 0b0000000000000000000000000000000000000000000000000000000000000010,
+	/// Metadata must be a URI (`scheme://...`) pointing to off-chain data.
+	OffchainMetadataOnly = 0b0000000000000000000000000000000000000000000000000000000000000100,
+	/// Item ids are allocated by the pallet in sequence through `mint_next`.
+	SequentialIds = 0b0000000000000000000000000000000000000000000000000000000000001000,
+	/// No two items of the collection may carry the same name. May only be switched while no
+	/// item of the collection has a name.
+	UniqueItemNames = 0b0000000000000000000000000000000000000000000000000000000000010000,
+	/// The configuration is final and may no longer be changed by the owner.
+	SettingsLocked = 0b0000000000000000000000000000000000000000000000000000000000100000,
+	/// The metadata, attributes and names within the collection may no longer be changed by
+	/// the owner, while items stay transferable. Once switched on, only `ForceOrigin` may switch
+	/// it off again.
+	ContentFrozen = 0b0000000000000000000000000000000000000000000000000000000001000000,
+	/// The first transfer of each item has to be approved by the Admin through
+	/// `approve_first_transfer`. Items which were transferred before are not affected.
+	RequireFirstTransferApproval =
+		0b0000000000000000000000000000000000000000000000000000000010000000,
+	/// Items are frozen once they have been transferred to a new owner, e.g. to bind them to
+	/// whoever claimed them. Moving an item into an escrow or wrapping it doesn't count.
+	FreezeAfterTransfer = 0b0000000000000000000000000000000000000000000000000000000100000000,
+	/// The items of the collection have been revealed, so items without metadata no longer show
+	/// the unrevealed placeholder.
+	Revealed = 0b0000000000000000000000000000000000000000000000000000001000000000,
+	/// The metadata of an item is frozen as soon as it is first set.
+	MetadataWriteOnce = 0b0000000000000000000000000000000000000000000000000000010000000000,
+	/// The metadata of the collection and its items is managed by the Issuer instead of the
+	/// owner.
+	IssuerManagesMetadata = 0b0000000000000000000000000000000000000000000000000000100000000000,
+	/// Every mint emits a `MintReceipt` event in addition to `Issued`.
+	MintReceipts = 0b0000000000000000000000000000000000000000000000000001000000000000,
+	/// Every minted item gets the `MINTED_AT_ATTRIBUTE` and `MINTED_BY_ATTRIBUTE` attributes.
+	StampMintProvenance = 0b0000000000000000000000000000000000000000000000000010000000000000,
+}
+
+/// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
+#[derive(Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct CollectionSettings(pub BitFlags<CollectionSetting>);
+
+impl CollectionSettings {
+	/// Settings with every flag switched off.
+	pub fn all_disabled() -> Self {
+		Self(BitFlags::EMPTY)
+	}
+
+	/// Returns `true` if `setting` is switched on.
+	pub fn is_enabled(&self, setting: CollectionSetting) -> bool {
+		self.0.contains(setting)
+	}
+}
+
+impl MaxEncodedLen for CollectionSettings {
+	fn max_encoded_len() -> usize {
+		u64::max_encoded_len()
+	}
+}
+
+impl Encode for CollectionSettings {
+	fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+		self.0.bits().using_encoded(f)
+	}
+}
+
+impl Decode for CollectionSettings {
+	fn decode<I: codec::Input>(input: &mut I) -> sp_std::result::Result<Self, codec::Error> {
+		let field = u64::decode(input)?;
+		Ok(Self(<BitFlags<CollectionSetting>>::from_bits(field).map_err(|_| "invalid value")?))
+	}
+}
+
+impl TypeInfo for CollectionSettings {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::builder()
+			.path(Path::new("BitFlags", module_path!()))
+			.type_params(vec![TypeParameter::new("T", Some(meta_type::<CollectionSetting>()))])
+			.composite(Fields::unnamed().field(|f| f.ty::<u64>().type_name("CollectionSetting")))
+	}
+}
+
+/// The categories of item-level events a collection may mute.
+///
+/// Muted events are not deposited at all, which keeps them out of blocks for high-volume
+/// collections. Indexers can't follow muted changes from events, so they have to read the state
+/// of the affected items from storage instead, e.g. their owners if `Transfers` is muted.
+/// Collection-level events, mints and burns are always deposited.
+#[bitflags]
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum EventCategory {
+	/// `Transferred`, `ApprovedTransfer` and `ApprovalCancelled`.
+	Transfers = 0b0001,
+	/// `MetadataSet` and `MetadataCleared`.
+	Metadata = 0b0010,
+	/// `AttributeSet` and `AttributeCleared` for the attributes of items.
+	Attributes = 0b0100,
+	/// `ItemPriceSet`, `ItemPriceRemoved` and `ItemBought`.
+	Trading = 0b1000,
+}
+
+/// Wrapper type for `BitFlags<EventCategory>` that implements `Codec`.
+#[derive(Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct EventMask(pub BitFlags<EventCategory>);
+
+impl EventMask {
+	/// Returns `true` if the events of `category` are muted.
+	pub fn is_muted(&self, category: EventCategory) -> bool {
+		self.0.contains(category)
+	}
+}
+
+impl MaxEncodedLen for EventMask {
+	fn max_encoded_len() -> usize {
+		u8::max_encoded_len()
+	}
+}
+
+impl Encode for EventMask {
+	fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+		self.0.bits().using_encoded(f)
+	}
+}
+
+impl Decode for EventMask {
+	fn decode<I: codec::Input>(input: &mut I) -> sp_std::result::Result<Self, codec::Error> {
+		let field = u8::decode(input)?;
+		Ok(Self(<BitFlags<EventCategory>>::from_bits(field).map_err(|_| "invalid value")?))
+	}
+}
+
+impl TypeInfo for EventMask {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::builder()
+			.path(Path::new("BitFlags", module_path!()))
+			.type_params(vec![TypeParameter::new("T", Some(meta_type::<EventCategory>()))])
+			.composite(Fields::unnamed().field(|f| f.ty::<u8>().type_name("EventCategory")))
+	}
+}
+
+/// Who may burn the items of a collection.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum BurnPolicy {
+	/// Only the owner of the item and the admin of the collection.
+	OwnerOnly,
+	/// As `OwnerOnly`, but also the approved delegate of the item.
+	ApprovedOrOwner,
+	/// Any signed origin, e.g. for items which decay or are consumed by other pallets.
+	Anyone,
+}
+
+impl Default for BurnPolicy {
+	fn default() -> Self {
+		Self::OwnerOnly
+	}
+}
+
+/// The bond posted by the owner of a collection.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionBond<Balance, BlockNumber> {
+	/// The amount reserved from the owner of the collection.
+	pub(super) amount: Balance,
+	/// The block in which the bond was last posted or slashed.
+	pub(super) clean_since: BlockNumber,
+}
+
+/// Which item ids may be minted within a collection, by their parity.
+///
+/// The parity of an id is given by [`Incrementable::is_odd`].
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum IdParity {
+	/// Any id.
+	Any,
+	/// Only even ids.
+	Even,
+	/// Only odd ids.
+	Odd,
+}
+
+impl Default for IdParity {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl IdParity {
+	/// Returns `true` if `id` has the parity required.
+	pub fn allows<ItemId: Incrementable>(&self, id: &ItemId) -> bool {
+		match self {
+			Self::Any => true,
+			Self::Even => !id.is_odd(),
+			Self::Odd => id.is_odd(),
+		}
+	}
+}
+
+/// The attribute an item has to carry for it to be burned.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BurnRequirement<Key, Value> {
+	/// The key of the attribute of the item.
+	pub key: Key,
+	/// The value the attribute must have.
+	pub value: Value,
+}
+
+/// The marketplace operators which may be approved to transfer the items of a collection.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum MarketplacePolicy<Set> {
+	/// Any account may be approved.
+	Any,
+	/// Only the accounts in the set may be approved.
+	Allowlist(Set),
+	/// Any account but those in the set may be approved.
+	Denylist(Set),
+}
+
+impl<Set> Default for MarketplacePolicy<Set> {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+/// The configuration of a collection.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionConfig<Balance, ItemId, CollectionId, BlockNumber, AccountId> {
+	/// The settings switched on for the collection.
+	pub settings: CollectionSettings,
+	/// The maximum number of items which may be minted within a single block, if limited.
+	pub max_mints_per_block: Option<u32>,
+	/// Who may burn the items of the collection.
+	pub burn_policy: BurnPolicy,
+	/// The amount reserved from the minter of an item for `MintStakeCooldown` blocks, if any.
+	pub mint_min_stake: Option<Balance>,
+	/// The highest id of the range reserved for items only the Issuer may mint, if any.
+	pub reserved_item_id_ceiling: Option<ItemId>,
+	/// The collection the receiver of a transfer must hold an item of, if any.
+	pub required_membership: Option<CollectionId>,
+	/// The maximum number of attributes across the collection and all of its items, if limited.
+	pub max_total_attributes: Option<u32>,
+	/// The number of blocks after which approvals to transfer an item expire at the latest, if
+	/// limited.
+	pub approval_ttl: Option<BlockNumber>,
+	/// The number of blocks which have to pass between two updates of the metadata of an item,
+	/// if limited.
+	pub metadata_update_cooldown: Option<BlockNumber>,
+	/// The account which burns the items transferred to it, if any.
+	pub burn_on_transfer_to: Option<AccountId>,
+	/// The parity the ids of newly minted items must have.
+	pub id_parity: IdParity,
+	/// The number of blocks which have to pass between two mints of the same account, if
+	/// limited.
+	pub mint_cooldown_per_account: Option<BlockNumber>,
+	/// The number of blocks after its mint within which the metadata of an item may be updated,
+	/// if limited.
+	pub metadata_mutable_until_age: Option<BlockNumber>,
+	/// The maximum number of distinct accounts which may hold items of the collection, if
+	/// limited.
+	pub max_distinct_owners: Option<u32>,
+	/// The number of blocks an item has to be held after it was minted or transferred before it
+	/// may be transferred again, if any.
+	pub holding_period: Option<BlockNumber>,
+	/// The account which may reclaim any item of the collection through `recover_item`, if any.
+	///
+	/// Disclosed by `has_recovery_account` of the collection's details. Once the collection has
+	/// items, only `ForceOrigin` may set or change it.
+	pub recovery_account: Option<AccountId>,
+	/// The fee charged from the owner of an item for listing it through `set_price`, if any.
+	pub listing_fee: Option<Balance>,
+	/// The number of distinct role holders which have to approve the thaw of an item through
+	/// `approve_unlock`. Up to one, the Admin may thaw items alone. A threshold above the number
+	/// of distinct role holders can't be met. Once the collection has items, only `ForceOrigin`
+	/// may change it.
+	pub unlock_threshold: u8,
+	/// The categories of item-level events which are not deposited.
+	pub muted_events: EventMask,
+}
+
+// Not derived, as that would require the ids to implement `Default`.
+impl<Balance, ItemId, CollectionId, BlockNumber, AccountId> Default
+	for CollectionConfig<Balance, ItemId, CollectionId, BlockNumber, AccountId>
+{
+	fn default() -> Self {
+		Self {
+			settings: Default::default(),
+			max_mints_per_block: None,
+			burn_policy: Default::default(),
+			mint_min_stake: None,
+			reserved_item_id_ceiling: None,
+			required_membership: None,
+			max_total_attributes: None,
+			approval_ttl: None,
+			metadata_update_cooldown: None,
+			burn_on_transfer_to: None,
+			id_parity: Default::default(),
+			mint_cooldown_per_account: None,
+			metadata_mutable_until_age: None,
+			max_distinct_owners: None,
+			holding_period: None,
+			recovery_account: None,
+			listing_fee: None,
+			unlock_threshold: 0,
+			muted_events: Default::default(),
+		}
+	}
 }
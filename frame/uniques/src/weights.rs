@@ -72,6 +72,40 @@ pub trait WeightInfo {
 	fn set_collection_max_supply() -> Weight;
 	fn set_price() -> Weight;
 	fn buy_item() -> Weight;
+	fn set_collection_config() -> Weight;
+	fn open_escrow() -> Weight;
+	fn fund_escrow() -> Weight;
+	fn confirm_escrow() -> Weight;
+	fn cancel_escrow() -> Weight;
+	fn expire_escrows(n: u32, ) -> Weight;
+	fn set_role_many(n: u32, ) -> Weight;
+	fn refresh_item_deposit() -> Weight;
+	fn mint_next() -> Weight;
+	fn release_mint_stakes(n: u32, ) -> Weight;
+	fn set_item_name() -> Weight;
+	fn lock_collection_recurring() -> Weight;
+	fn cancel_recurring_lock() -> Weight;
+	fn cycle_recurring_locks(n: u32, ) -> Weight;
+	fn freeze_collection_content() -> Weight;
+	fn wrap_item() -> Weight;
+	fn unwrap_item() -> Weight;
+	fn approve_first_transfer() -> Weight;
+	fn set_unrevealed_metadata() -> Weight;
+	fn reveal_collection() -> Weight;
+	fn set_marketplace_policy() -> Weight;
+	fn commit_reveal_hash() -> Weight;
+	fn force_transfer() -> Weight;
+	fn make_item_soulbound() -> Weight;
+	fn mint_and_list() -> Weight;
+	fn transfer_many_to(n: u32, ) -> Weight;
+	fn set_collection_bond() -> Weight;
+	fn slash_collection_bond() -> Weight;
+	fn release_collection_bond() -> Weight;
+	fn transfer_as_delegate() -> Weight;
+	fn set_burn_requirement() -> Weight;
+	fn clear_all_collection_approvals(n: u32, ) -> Weight;
+	fn recover_item() -> Weight;
+	fn approve_unlock() -> Weight;
 }
 
 /// Weights for pallet_uniques using the Substrate node and recommended hardware.
@@ -120,29 +154,60 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques MintsThisBlockOf (r:1 w:1)
+	// Storage: Uniques LastMintByAccountOf (r:1 w:1)
+	// Storage: Uniques MintStakeOf (r:1 w:1)
+	// Storage: Uniques Attribute (r:2 w:2)
 	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ItemMintedAt (r:0 w:1)
 	fn mint() -> Weight {
-		Weight::from_ref_time(42_146_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(3 as u64))
+		Weight::from_ref_time(71_384_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques WrappedCountOf (r:1 w:0)
+	// Storage: Uniques BurnRequirementOf (r:1 w:0)
+	// Storage: Uniques Attribute (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques ItemNameOf (r:1 w:1)
+	// Storage: Uniques ItemByNameOf (r:1 w:1)
 	// Storage: Uniques Account (r:0 w:1)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: Uniques FirstTransferApprovedOf (r:0 w:1)
+	// Storage: Uniques SoulboundItemsOf (r:0 w:1)
+	// Storage: Uniques UnlockApprovalsOf (r:0 w:1)
+	// Storage: Uniques TransferCountOf (r:0 w:1)
+	// Storage: Uniques LastMetadataUpdateOf (r:0 w:1)
+	// Storage: Uniques ItemMintedAt (r:0 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
 	fn burn() -> Weight {
-		Weight::from_ref_time(42_960_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+		Weight::from_ref_time(63_517_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
 	}
 	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques FirstTransferApprovedOf (r:1 w:0)
+	// Storage: Uniques LastTransferOf (r:1 w:1)
+	// Storage: Uniques ItemMintedAt (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
 	// Storage: Uniques Account (r:0 w:2)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	fn transfer() -> Weight {
-		Weight::from_ref_time(33_025_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+		Weight::from_ref_time(48_702_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques Asset (r:100 w:100)
@@ -240,12 +305,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
-	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques ClassMetadataOf (r:1 w:1)
 	fn clear_collection_metadata() -> Weight {
-		Weight::from_ref_time(35_621_000 as u64)
+		Weight::from_ref_time(37_104_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: Uniques Class (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
@@ -290,6 +355,371 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
+	// Storage: Uniques Class (r:2 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:1)
+	// Storage: Uniques ItemNameOf (r:1 w:0)
+	fn set_collection_config() -> Weight {
+		Weight::from_ref_time(21_417_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques EscrowExpiries (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn open_escrow() -> Weight {
+		Weight::from_ref_time(47_893_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn fund_escrow() -> Weight {
+		Weight::from_ref_time(30_154_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: Uniques EscrowExpiries (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn confirm_escrow() -> Weight {
+		Weight::from_ref_time(64_215_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: Uniques EscrowExpiries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	fn cancel_escrow() -> Weight {
+		Weight::from_ref_time(44_860_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: Uniques EscrowExpiries (r:1 w:1)
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_escrows(n: u32, ) -> Weight {
+		Weight::from_ref_time(3_915_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(27_650_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Class (r:1 w:1)
+	/// The range of component `n` is `[1, 64]`.
+	fn set_role_many(n: u32, ) -> Weight {
+		Weight::from_ref_time(9_830_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(14_726_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	fn refresh_item_deposit() -> Weight {
+		Weight::from_ref_time(24_310_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques NextItemIdOf (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ItemMintedAt (r:0 w:1)
+	fn mint_next() -> Weight {
+		Weight::from_ref_time(49_136_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Uniques MintStakeOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn release_mint_stakes(n: u32, ) -> Weight {
+		Weight::from_ref_time(3_210_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(18_540_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques ItemByNameOf (r:2 w:2)
+	// Storage: Uniques ItemNameOf (r:1 w:1)
+	fn set_item_name() -> Weight {
+		Weight::from_ref_time(27_940_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques RecurringLocksOf (r:1 w:1)
+	// Storage: Uniques CounterForRecurringLocksOf (r:1 w:1)
+	// Storage: Uniques RecurringLockTransitions (r:1 w:1)
+	fn lock_collection_recurring() -> Weight {
+		Weight::from_ref_time(31_460_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques RecurringLocksOf (r:1 w:1)
+	// Storage: Uniques CounterForRecurringLocksOf (r:1 w:1)
+	// Storage: Uniques RecurringLockTransitions (r:1 w:1)
+	fn cancel_recurring_lock() -> Weight {
+		Weight::from_ref_time(24_180_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Uniques RecurringLockTransitions (r:2 w:2)
+	// Storage: Uniques RecurringLocksOf (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	/// The range of component `n` is `[0, 64]`.
+	fn cycle_recurring_locks(n: u32, ) -> Weight {
+		Weight::from_ref_time(3_640_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(16_920_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:1)
+	fn freeze_collection_content() -> Weight {
+		Weight::from_ref_time(18_370_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques WrappedCountOf (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn wrap_item() -> Weight {
+		Weight::from_ref_time(41_250_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques WrappedItemsOf (r:2 w:1)
+	// Storage: Uniques Asset (r:2 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques WrappedCountOf (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn unwrap_item() -> Weight {
+		Weight::from_ref_time(40_380_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques FirstTransferApprovedOf (r:0 w:1)
+	fn approve_first_transfer() -> Weight {
+		Weight::from_ref_time(17_930_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques UnrevealedMetadataOf (r:0 w:1)
+	fn set_unrevealed_metadata() -> Weight {
+		Weight::from_ref_time(21_470_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques RevealCommitmentOf (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:1)
+	// Storage: Uniques UnrevealedMetadataOf (r:0 w:1)
+	fn reveal_collection() -> Weight {
+		Weight::from_ref_time(23_940_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques MarketplacePolicyOf (r:0 w:1)
+	fn set_marketplace_policy() -> Weight {
+		Weight::from_ref_time(19_870_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques RevealCommitmentOf (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	fn commit_reveal_hash() -> Weight {
+		Weight::from_ref_time(19_520_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
+	fn force_transfer() -> Weight {
+		Weight::from_ref_time(36_271_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques SoulboundItemsOf (r:0 w:1)
+	fn make_item_soulbound() -> Weight {
+		Weight::from_ref_time(27_350_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ItemMintedAt (r:0 w:1)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn mint_and_list() -> Weight {
+		Weight::from_ref_time(61_058_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:64 w:0)
+	// Storage: Uniques WrappedItemsOf (r:64 w:0)
+	// Storage: Uniques Asset (r:64 w:64)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:64 w:64)
+	// Storage: Uniques Account (r:0 w:128)
+	// Storage: Uniques ItemPriceOf (r:0 w:64)
+	// Storage: Uniques LastTransferOf (r:0 w:64)
+	/// The range of component `n` is `[1, 64]`.
+	fn transfer_many_to(n: u32, ) -> Weight {
+		Weight::from_ref_time(14_083_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(36_947_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionBondOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_collection_bond() -> Weight {
+		Weight::from_ref_time(27_318_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionBondOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn slash_collection_bond() -> Weight {
+		Weight::from_ref_time(31_904_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionBondOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn release_collection_bond() -> Weight {
+		Weight::from_ref_time(28_662_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
+	fn transfer_as_delegate() -> Weight {
+		Weight::from_ref_time(41_539_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques BurnRequirementOf (r:0 w:1)
+	fn set_burn_requirement() -> Weight {
+		Weight::from_ref_time(20_331_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1001 w:1000)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_all_collection_approvals(n: u32, ) -> Weight {
+		Weight::from_ref_time(17_482_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(3_936_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
+	fn recover_item() -> Weight {
+		Weight::from_ref_time(35_874_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques UnlockApprovalsOf (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques SoulboundItemsOf (r:1 w:0)
+	fn approve_unlock() -> Weight {
+		Weight::from_ref_time(29_870_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -337,29 +767,60 @@ impl WeightInfo for () {
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques MintsThisBlockOf (r:1 w:1)
+	// Storage: Uniques LastMintByAccountOf (r:1 w:1)
+	// Storage: Uniques MintStakeOf (r:1 w:1)
+	// Storage: Uniques Attribute (r:2 w:2)
 	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ItemMintedAt (r:0 w:1)
 	fn mint() -> Weight {
-		Weight::from_ref_time(42_146_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+		Weight::from_ref_time(71_384_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques WrappedCountOf (r:1 w:0)
+	// Storage: Uniques BurnRequirementOf (r:1 w:0)
+	// Storage: Uniques Attribute (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques ItemNameOf (r:1 w:1)
+	// Storage: Uniques ItemByNameOf (r:1 w:1)
 	// Storage: Uniques Account (r:0 w:1)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: Uniques FirstTransferApprovedOf (r:0 w:1)
+	// Storage: Uniques SoulboundItemsOf (r:0 w:1)
+	// Storage: Uniques UnlockApprovalsOf (r:0 w:1)
+	// Storage: Uniques TransferCountOf (r:0 w:1)
+	// Storage: Uniques LastMetadataUpdateOf (r:0 w:1)
+	// Storage: Uniques ItemMintedAt (r:0 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
 	fn burn() -> Weight {
-		Weight::from_ref_time(42_960_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+		Weight::from_ref_time(63_517_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
 	}
 	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques FirstTransferApprovedOf (r:1 w:0)
+	// Storage: Uniques LastTransferOf (r:1 w:1)
+	// Storage: Uniques ItemMintedAt (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
 	// Storage: Uniques Account (r:0 w:2)
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	fn transfer() -> Weight {
-		Weight::from_ref_time(33_025_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+		Weight::from_ref_time(48_702_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 	}
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques Asset (r:100 w:100)
@@ -457,12 +918,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
-	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques ClassMetadataOf (r:1 w:1)
 	fn clear_collection_metadata() -> Weight {
-		Weight::from_ref_time(35_621_000 as u64)
+		Weight::from_ref_time(37_104_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	// Storage: Uniques Class (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
@@ -507,4 +968,369 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
+	// Storage: Uniques Class (r:2 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:1)
+	// Storage: Uniques ItemNameOf (r:1 w:0)
+	fn set_collection_config() -> Weight {
+		Weight::from_ref_time(21_417_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques EscrowExpiries (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn open_escrow() -> Weight {
+		Weight::from_ref_time(47_893_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn fund_escrow() -> Weight {
+		Weight::from_ref_time(30_154_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: Uniques EscrowExpiries (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn confirm_escrow() -> Weight {
+		Weight::from_ref_time(64_215_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: Uniques EscrowExpiries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	fn cancel_escrow() -> Weight {
+		Weight::from_ref_time(44_860_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: Uniques EscrowExpiries (r:1 w:1)
+	// Storage: Uniques EscrowsOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_escrows(n: u32, ) -> Weight {
+		Weight::from_ref_time(3_915_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(27_650_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Class (r:1 w:1)
+	/// The range of component `n` is `[1, 64]`.
+	fn set_role_many(n: u32, ) -> Weight {
+		Weight::from_ref_time(9_830_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(14_726_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	fn refresh_item_deposit() -> Weight {
+		Weight::from_ref_time(24_310_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques NextItemIdOf (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ItemMintedAt (r:0 w:1)
+	fn mint_next() -> Weight {
+		Weight::from_ref_time(49_136_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Uniques MintStakeOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	fn release_mint_stakes(n: u32, ) -> Weight {
+		Weight::from_ref_time(3_210_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(18_540_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques ItemByNameOf (r:2 w:2)
+	// Storage: Uniques ItemNameOf (r:1 w:1)
+	fn set_item_name() -> Weight {
+		Weight::from_ref_time(27_940_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques RecurringLocksOf (r:1 w:1)
+	// Storage: Uniques CounterForRecurringLocksOf (r:1 w:1)
+	// Storage: Uniques RecurringLockTransitions (r:1 w:1)
+	fn lock_collection_recurring() -> Weight {
+		Weight::from_ref_time(31_460_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques RecurringLocksOf (r:1 w:1)
+	// Storage: Uniques CounterForRecurringLocksOf (r:1 w:1)
+	// Storage: Uniques RecurringLockTransitions (r:1 w:1)
+	fn cancel_recurring_lock() -> Weight {
+		Weight::from_ref_time(24_180_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Uniques RecurringLockTransitions (r:2 w:2)
+	// Storage: Uniques RecurringLocksOf (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	/// The range of component `n` is `[0, 64]`.
+	fn cycle_recurring_locks(n: u32, ) -> Weight {
+		Weight::from_ref_time(3_640_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(16_920_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:1)
+	fn freeze_collection_content() -> Weight {
+		Weight::from_ref_time(18_370_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques WrappedCountOf (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn wrap_item() -> Weight {
+		Weight::from_ref_time(41_250_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques WrappedItemsOf (r:2 w:1)
+	// Storage: Uniques Asset (r:2 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques WrappedCountOf (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn unwrap_item() -> Weight {
+		Weight::from_ref_time(40_380_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques FirstTransferApprovedOf (r:0 w:1)
+	fn approve_first_transfer() -> Weight {
+		Weight::from_ref_time(17_930_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques UnrevealedMetadataOf (r:0 w:1)
+	fn set_unrevealed_metadata() -> Weight {
+		Weight::from_ref_time(21_470_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques RevealCommitmentOf (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:1)
+	// Storage: Uniques UnrevealedMetadataOf (r:0 w:1)
+	fn reveal_collection() -> Weight {
+		Weight::from_ref_time(23_940_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques MarketplacePolicyOf (r:0 w:1)
+	fn set_marketplace_policy() -> Weight {
+		Weight::from_ref_time(19_870_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques RevealCommitmentOf (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	fn commit_reveal_hash() -> Weight {
+		Weight::from_ref_time(19_520_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
+	fn force_transfer() -> Weight {
+		Weight::from_ref_time(36_271_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques SoulboundItemsOf (r:0 w:1)
+	fn make_item_soulbound() -> Weight {
+		Weight::from_ref_time(27_350_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques ItemMintedAt (r:0 w:1)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn mint_and_list() -> Weight {
+		Weight::from_ref_time(61_058_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:64 w:0)
+	// Storage: Uniques WrappedItemsOf (r:64 w:0)
+	// Storage: Uniques Asset (r:64 w:64)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:64 w:64)
+	// Storage: Uniques Account (r:0 w:128)
+	// Storage: Uniques ItemPriceOf (r:0 w:64)
+	// Storage: Uniques LastTransferOf (r:0 w:64)
+	/// The range of component `n` is `[1, 64]`.
+	fn transfer_many_to(n: u32, ) -> Weight {
+		Weight::from_ref_time(14_083_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(36_947_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionBondOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_collection_bond() -> Weight {
+		Weight::from_ref_time(27_318_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionBondOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn slash_collection_bond() -> Weight {
+		Weight::from_ref_time(31_904_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionBondOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn release_collection_bond() -> Weight {
+		Weight::from_ref_time(28_662_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
+	fn transfer_as_delegate() -> Weight {
+		Weight::from_ref_time(41_539_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques BurnRequirementOf (r:0 w:1)
+	fn set_burn_requirement() -> Weight {
+		Weight::from_ref_time(20_331_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1001 w:1000)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_all_collection_approvals(n: u32, ) -> Weight {
+		Weight::from_ref_time(17_482_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(3_936_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques EscrowsOf (r:1 w:0)
+	// Storage: Uniques WrappedItemsOf (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques DistinctOwnersCountOf (r:1 w:1)
+	// Storage: Uniques TransferCountOf (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: Uniques LastTransferOf (r:0 w:1)
+	fn recover_item() -> Weight {
+		Weight::from_ref_time(35_874_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques UnlockApprovalsOf (r:1 w:1)
+	// Storage: Uniques CollectionConfigOf (r:1 w:0)
+	// Storage: Uniques SoulboundItemsOf (r:1 w:0)
+	fn approve_unlock() -> Weight {
+		Weight::from_ref_time(29_870_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
 }
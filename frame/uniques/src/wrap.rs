@@ -0,0 +1,100 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functionality for wrapping items inside other items.
+
+use super::*;
+use frame_support::{ensure, traits::Get, PalletId};
+use sp_runtime::{traits::AccountIdConversion, DispatchResult};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The account holding the items wrapped inside `parent`.
+	pub fn wrap_account(parent: (T::CollectionId, T::ItemId)) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(parent)
+	}
+
+	/// Returns `true` if `who` is the wrap account of some item.
+	pub fn is_wrap_account(who: &T::AccountId) -> bool {
+		<PalletId as AccountIdConversion<T::AccountId>>::try_from_sub_account::<(
+			T::CollectionId,
+			T::ItemId,
+		)>(who)
+		.map_or(false, |(id, _)| id == T::PalletId::get())
+	}
+
+	pub fn do_wrap_item(
+		parent: (T::CollectionId, T::ItemId),
+		child: (T::CollectionId, T::ItemId),
+		sender: T::AccountId,
+	) -> DispatchResult {
+		ensure!(parent != child, Error::<T, I>::WrapIntoSelf);
+		let parent_details =
+			Item::<T, I>::get(&parent.0, &parent.1).ok_or(Error::<T, I>::UnknownItem)?;
+		// a wrapped parent is owned by a wrap account, which rules out cycles.
+		ensure!(parent_details.owner == sender, Error::<T, I>::NoPermission);
+
+		// the wrap account only takes custody of the item, so this is not a transfer.
+		let (_, details) = Self::ensure_item_movable(child.0, child.1)?;
+		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
+		Self::move_into_custody(child.0, child.1, details, Self::wrap_account(parent));
+		WrappedItemsOf::<T, I>::insert(&child.0, &child.1, parent);
+		WrappedCountOf::<T, I>::mutate(&parent.0, &parent.1, |count| count.saturating_inc());
+
+		Self::deposit_event(Event::ItemWrapped {
+			collection: child.0,
+			item: child.1,
+			parent_collection: parent.0,
+			parent_item: parent.1,
+		});
+		Ok(())
+	}
+
+	pub fn do_unwrap_item(
+		child: (T::CollectionId, T::ItemId),
+		sender: T::AccountId,
+	) -> DispatchResult {
+		let parent =
+			WrappedItemsOf::<T, I>::get(&child.0, &child.1).ok_or(Error::<T, I>::NotWrapped)?;
+		let owner = Item::<T, I>::get(&parent.0, &parent.1)
+			.map(|details| details.owner)
+			.ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(owner == sender, Error::<T, I>::NoPermission);
+
+		WrappedItemsOf::<T, I>::remove(&child.0, &child.1);
+		let (_, details) = Self::ensure_item_movable(child.0, child.1)?;
+		Self::move_into_custody(child.0, child.1, details, owner.clone());
+		WrappedCountOf::<T, I>::mutate_exists(&parent.0, &parent.1, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+		});
+
+		Self::deposit_event(Event::ItemUnwrapped { collection: child.0, item: child.1, owner });
+		Ok(())
+	}
+
+	/// Ensure that the item neither is wrapped nor wraps other items.
+	pub(crate) fn ensure_unwrapped(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> DispatchResult {
+		ensure!(!WrappedItemsOf::<T, I>::contains_key(collection, item), Error::<T, I>::Wrapped);
+		ensure!(
+			!WrappedCountOf::<T, I>::contains_key(collection, item),
+			Error::<T, I>::HasWrappedItems
+		);
+		Ok(())
+	}
+}
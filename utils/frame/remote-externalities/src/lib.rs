@@ -250,6 +250,8 @@ pub struct Builder<B: BlockT> {
 	hashed_keys: Vec<Vec<u8>>,
 	/// The keys that will be excluded from the final externality. The *hashed* key must be given.
 	hashed_blacklist: Vec<Vec<u8>>,
+	/// If set, only the injected hashed keys and prefixes are scraped, and no pallet data.
+	only_injected: bool,
 	/// connectivity mode, online or offline.
 	mode: Mode<B>,
 	/// The state version being used.
@@ -266,6 +268,7 @@ impl<B: BlockT + DeserializeOwned> Default for Builder<B> {
 			hashed_prefixes: Default::default(),
 			hashed_keys: Default::default(),
 			hashed_blacklist: Default::default(),
+			only_injected: false,
 			state_version: StateVersion::V1,
 		}
 	}
@@ -627,7 +630,10 @@ impl<B: BlockT + DeserializeOwned> Builder<B> {
 			.expect("online config must be initialized by this point; qed.");
 		log::info!(target: LOG_TARGET, "scraping key-pairs from remote @ {:?}", at);
 
-		let mut keys_and_values = if config.pallets.len() > 0 {
+		let mut keys_and_values = if self.only_injected {
+			log::info!(target: LOG_TARGET, "downloading only the injected keys and prefixes.");
+			vec![]
+		} else if config.pallets.len() > 0 {
 			let mut filtered_kv = vec![];
 			for p in config.pallets.iter() {
 				let hashed_prefix = StorageKey(twox_128(p.as_bytes()).to_vec());
@@ -795,6 +801,15 @@ impl<B: BlockT + DeserializeOwned> Builder<B> {
 		self
 	}
 
+	/// Only scrape the injected hashed keys and prefixes, ignoring [`OnlineConfig::pallets`].
+	///
+	/// Child-trees whose roots are among the injected keys are still scraped, if
+	/// [`OnlineConfig::scrape_children`] is set.
+	pub fn only_injected(mut self) -> Self {
+		self.only_injected = true;
+		self
+	}
+
 	/// Blacklist this hashed key from the final externalities. This is treated as-is, and should be
 	/// pre-hashed.
 	pub fn blacklist_hashed_key(mut self, hashed: &[u8]) -> Self {
@@ -0,0 +1,160 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	build_executor, extract_code, full_extensions, parse, state_machine_call,
+	state_machine_call_with_proof, SharedParams, State, LOG_TARGET,
+};
+use parity_scale_codec::{Decode, Encode};
+use remote_externalities::TestExternalities;
+use sc_executor::{NativeElseWasmExecutor, NativeExecutionDispatch};
+use sc_service::Configuration;
+use sp_core::{hashing::blake2_64, storage::well_known_keys, twox_128};
+use sp_runtime::{
+	traits::{Block as BlockT, NumberFor},
+	ApplyExtrinsicResult,
+};
+use sp_state_machine::Backend;
+use sp_version::RuntimeVersion;
+use sp_weights::Weight;
+use std::{fmt::Debug, str::FromStr};
+
+/// Configurations of the [`Command::ApplyExtrinsic`].
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ApplyExtrinsicCmd {
+	/// The hex encoded extrinsic to apply.
+	#[clap(long, parse(try_from_str = parse::hex_bytes))]
+	pub hex: Vec<u8>,
+
+	/// Overwrite the wasm code in state or not.
+	#[clap(long)]
+	overwrite_wasm_code: bool,
+
+	/// The state type to use.
+	#[clap(subcommand)]
+	pub state: State,
+}
+
+pub(crate) async fn apply_extrinsic<Block, ExecDispatch>(
+	shared: SharedParams,
+	command: ApplyExtrinsicCmd,
+	config: Configuration,
+) -> sc_cli::Result<()>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	Block::Hash: FromStr,
+	<Block::Hash as FromStr>::Err: Debug,
+	NumberFor<Block>: FromStr,
+	<NumberFor<Block> as FromStr>::Err: Debug,
+	ExecDispatch: NativeExecutionDispatch + 'static,
+{
+	let executor = build_executor(&shared, &config);
+	let execution = shared.execution;
+
+	let extrinsic = <Block::Extrinsic as Decode>::decode(&mut &*command.hex)
+		.map_err(|e| format!("failed to decode extrinsic: {:?}", e))?;
+
+	let ext = {
+		let builder = command.state.builder::<Block>()?.state_version(shared.state_version);
+		let builder = if command.overwrite_wasm_code {
+			log::info!(
+				target: LOG_TARGET,
+				"replacing the in-storage :code: with the local code from {}'s chain_spec (your local repo)",
+				config.chain_spec.name(),
+			);
+			let (code_key, code) = extract_code(&config.chain_spec)?;
+			builder.inject_hashed_key_value(&[(code_key, code)])
+		} else {
+			builder.inject_hashed_key(well_known_keys::CODE)
+		};
+		builder.build().await?
+	};
+
+	let block_weight_key = [twox_128(b"System"), twox_128(b"BlockWeight")].concat();
+	let weight_before = ext
+		.backend
+		.storage(&block_weight_key)
+		.map_err(|e| format!("failed to read the block weight: {:?}", e))?
+		.map(|encoded| total_block_weight(&encoded))
+		.transpose()?
+		.unwrap_or_default();
+
+	// prefer the try-runtime api, if the runtime exposes one.
+	let method = if has_try_runtime_api::<Block, ExecDispatch>(&ext, &executor, execution)? {
+		"TryRuntime_apply_extrinsic"
+	} else {
+		log::info!(
+			target: LOG_TARGET,
+			"runtime does not provide the TryRuntime api, using BlockBuilder_apply_extrinsic",
+		);
+		"BlockBuilder_apply_extrinsic"
+	};
+	let (changes, encoded_result) = state_machine_call_with_proof::<Block, ExecDispatch>(
+		&ext,
+		&executor,
+		execution,
+		method,
+		&extrinsic.encode(),
+		full_extensions(),
+	)?;
+
+	let result = <ApplyExtrinsicResult as Decode>::decode(&mut &*encoded_result)
+		.map_err(|e| format!("failed to decode apply extrinsic result: {:?}", e))?;
+	let weight_after = match changes.storage(&block_weight_key) {
+		Some(Some(encoded)) => total_block_weight(encoded)?,
+		Some(None) => Weight::zero(),
+		None => weight_before,
+	};
+	let consumed = weight_after.saturating_sub(weight_before);
+
+	log::info!(
+		target: LOG_TARGET,
+		"extrinsic applied with result {:?}. Consumed weight = ({} ps, {} byte).",
+		result,
+		consumed.ref_time(),
+		consumed.proof_size(),
+	);
+
+	Ok(())
+}
+
+/// Whether the runtime in `ext` declares the `TryRuntime` api in its version.
+fn has_try_runtime_api<Block: BlockT, ExecDispatch: NativeExecutionDispatch + 'static>(
+	ext: &TestExternalities,
+	executor: &NativeElseWasmExecutor<ExecDispatch>,
+	execution: sc_cli::ExecutionStrategy,
+) -> sc_cli::Result<bool> {
+	let (_, encoded) = state_machine_call::<Block, ExecDispatch>(
+		ext,
+		executor,
+		execution,
+		"Core_version",
+		&[],
+		Default::default(),
+	)?;
+	let version = <RuntimeVersion as Decode>::decode(&mut &*encoded)
+		.map_err(|e| format!("failed to decode runtime version: {:?}", e))?;
+	Ok(version.has_api_with(&blake2_64(b"TryRuntime"), |_| true))
+}
+
+/// Sum up the weight of all dispatch classes in the encoded `System::BlockWeight`.
+fn total_block_weight(mut encoded: &[u8]) -> sc_cli::Result<Weight> {
+	let (normal, operational, mandatory) =
+		<(Weight, Weight, Weight) as Decode>::decode(&mut encoded)
+			.map_err(|e| format!("failed to decode block weight: {:?}", e))?;
+	Ok(normal.saturating_add(operational).saturating_add(mandatory))
+}
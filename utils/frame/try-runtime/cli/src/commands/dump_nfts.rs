@@ -0,0 +1,156 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	build_executor,
+	metadata::{decode_all, runtime_metadata, storage_types},
+	parse, SharedParams, State, LOG_TARGET,
+};
+use frame_metadata::RuntimeMetadataV14;
+use remote_externalities::TestExternalities;
+use sc_executor::NativeExecutionDispatch;
+use sc_service::Configuration;
+use sp_core::{blake2_128, storage::well_known_keys, twox_128};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use sp_state_machine::Backend;
+use std::{fmt::Debug, str::FromStr};
+
+/// The maps of the uniques pallet keyed by the collection id alone.
+const COLLECTION_MAPS: [&str; 5] = [
+	"Class",
+	"ClassMetadataOf",
+	"CollectionConfigOf",
+	"CollectionMaxSupply",
+	"UnrevealedMetadataOf",
+];
+
+/// Configurations of the [`Command::DumpNfts`].
+#[derive(Debug, Clone, clap::Parser)]
+pub struct DumpNftsCmd {
+	/// The hex encoded, SCALE encoded id of the collection to report.
+	#[clap(long, parse(try_from_str = parse::hex_bytes))]
+	pub collection: Vec<u8>,
+
+	/// The name under which the uniques pallet is part of the runtime.
+	#[clap(long, default_value = "Uniques")]
+	pub pallet_name: String,
+
+	/// The state type to use.
+	#[clap(subcommand)]
+	pub state: State,
+}
+
+pub(crate) async fn dump_nfts<Block, ExecDispatch>(
+	shared: SharedParams,
+	command: DumpNftsCmd,
+	config: Configuration,
+) -> sc_cli::Result<()>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	Block::Hash: FromStr,
+	<Block::Hash as FromStr>::Err: Debug,
+	NumberFor<Block>: FromStr,
+	<NumberFor<Block> as FromStr>::Err: Debug,
+	ExecDispatch: NativeExecutionDispatch + 'static,
+{
+	let executor = build_executor(&shared, &config);
+
+	// the values are decoded against the metadata of the code in state, not the local one.
+	let ext = command
+		.state
+		.builder::<Block>()?
+		.state_version(shared.state_version)
+		.inject_hashed_key(well_known_keys::CODE)
+		.build()
+		.await?;
+	let metadata = runtime_metadata::<Block, ExecDispatch>(&ext, &executor, shared.execution)?;
+	let pallet = command.pallet_name.as_str();
+	let collection_key = blake2_128_concat(&command.collection);
+
+	let (item_keys, _) = storage_types(&metadata, pallet, "Asset")?;
+	let (collection_ty, item_ty) = match item_keys[..] {
+		[collection_ty, item_ty] => (collection_ty, item_ty),
+		_ => return Err(format!("{}::Asset is not keyed by collection and item", pallet).into()),
+	};
+	let collection = decode_all(&metadata.types, collection_ty, &command.collection)
+		.map_err(|e| format!("failed to decode the collection id: {}", e))?;
+
+	log::info!(target: LOG_TARGET, "collection {} of {}:", collection, pallet);
+	for map in COLLECTION_MAPS {
+		let key = [&storage_prefix(pallet, map)[..], &collection_key].concat();
+		log::info!(target: LOG_TARGET, "{:<24} {}", map, display_value(&ext, &metadata, pallet, map, &key)?);
+	}
+
+	let items_prefix = [&storage_prefix(pallet, "Asset")[..], &collection_key].concat();
+	let items = ext.backend.keys(&items_prefix);
+	log::info!(target: LOG_TARGET, "{} items:", items.len());
+	for key in items {
+		// the item id follows the 16 byte hash of the `Blake2_128Concat` key.
+		let item_key = &key[items_prefix.len()..];
+		let item = decode_all(&metadata.types, item_ty, item_key.get(16..).unwrap_or_default())
+			.map_err(|e| format!("failed to decode an item id: {}", e))?;
+		let metadata_key =
+			[&storage_prefix(pallet, "InstanceMetadataOf")[..], &collection_key, item_key].concat();
+		log::info!(
+			target: LOG_TARGET,
+			"item {}: details {}, metadata {}",
+			item,
+			display_value(&ext, &metadata, pallet, "Asset", &key)?,
+			display_value(&ext, &metadata, pallet, "InstanceMetadataOf", &metadata_key)?,
+		);
+	}
+
+	Ok(())
+}
+
+/// The prefix of the storage item `storage` of `pallet`.
+fn storage_prefix(pallet: &str, storage: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(storage.as_bytes())].concat()
+}
+
+/// The `Blake2_128Concat` hash of `key`.
+fn blake2_128_concat(key: &[u8]) -> Vec<u8> {
+	[&blake2_128(key)[..], key].concat()
+}
+
+/// The value stored under `key` in `ext`, decoded as a value of the storage item `storage` of
+/// `pallet`.
+///
+/// Absent values are shown as `<none>`, and storage items the runtime doesn't have as
+/// `<not in metadata>`.
+fn display_value(
+	ext: &TestExternalities,
+	metadata: &RuntimeMetadataV14,
+	pallet: &str,
+	storage: &str,
+	key: &[u8],
+) -> sc_cli::Result<String> {
+	let value_ty = match storage_types(metadata, pallet, storage) {
+		Ok((_, value_ty)) => value_ty,
+		Err(_) => return Ok("<not in metadata>".into()),
+	};
+	let value = ext
+		.backend
+		.storage(key)
+		.map_err(|e| format!("failed to read storage: {:?}", e))?;
+	Ok(match value {
+		Some(value) => decode_all(&metadata.types, value_ty, &value)
+			.map_err(|e| format!("failed to decode {}::{}: {}", pallet, storage, e))?
+			.to_string(),
+		None => "<none>".into(),
+	})
+}
@@ -15,7 +15,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub(crate) mod apply_extrinsic;
+pub(crate) mod dump_nfts;
 pub(crate) mod execute_block;
 pub(crate) mod follow_chain;
 pub(crate) mod offchain_worker;
 pub(crate) mod on_runtime_upgrade;
+pub(crate) mod storage_versions;
@@ -15,22 +15,181 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{fmt::Debug, str::FromStr};
+use std::{
+	collections::BTreeMap,
+	fmt::Debug,
+	path::PathBuf,
+	str::FromStr,
+	sync::{mpsc, Arc, Mutex},
+	time::Duration,
+};
 
-use parity_scale_codec::Decode;
-use sc_executor::NativeExecutionDispatch;
+use frame_metadata::RuntimeMetadataV14;
+use parity_scale_codec::{Decode, Encode};
+use remote_externalities::TestExternalities;
+use sc_executor::{NativeElseWasmExecutor, NativeExecutionDispatch};
 use sc_service::Configuration;
-use sp_runtime::traits::{Block as BlockT, NumberFor};
+use scale_info::TypeDef;
+use sp_core::{
+	hexdisplay::HexDisplay,
+	storage::{well_known_keys, ChildInfo, StorageData, StorageKey},
+	twox_128,
+};
+use sp_runtime::traits::{BlakeTwo256, Block as BlockT, NumberFor};
+use sp_state_machine::{Backend, OverlayedChanges, StorageCollection, StorageProof};
 use sp_weights::Weight;
 
 use crate::{
-	build_executor, ensure_matching_spec, extract_code, local_spec, state_machine_call_with_proof,
-	SharedParams, State, LOG_TARGET,
+	build_executor,
+	commands::storage_versions::{pallet_names, storage_version_key},
+	ensure_matching_spec, extract_code, hash_of, local_spec,
+	metadata::{decode_all, runtime_metadata, storage_types, Value},
+	state_machine_call, state_machine_call_with_storage_proof, SharedParams, State, LOG_TARGET,
 };
 
+/// The runtime api that, if present, reports the weight consumed by each individual migration.
+const BREAKDOWN_METHOD: &str = "TryRuntime_on_runtime_upgrade_breakdown";
+
 /// Configurations of the [`Command::OnRuntimeUpgrade`].
 #[derive(Debug, Clone, clap::Parser)]
 pub struct OnRuntimeUpgradeCmd {
+	/// Flag any single migration whose `ref_time` weight exceeds this value (in picoseconds).
+	///
+	/// Only has an effect if the runtime reports a per-migration weight breakdown via
+	/// `TryRuntime_on_runtime_upgrade_breakdown`.
+	#[clap(long)]
+	pub per_migration_max: Option<u64>,
+
+	/// Only fetch the given child-trie from the live chain, instead of the whole state.
+	///
+	/// The hex encoded key of a default child-trie, with or without the `:child_storage:default:`
+	/// prefix. The code is always taken from the local runtime. Any top-level storage the
+	/// migration reads will be absent. Only has an effect with the `live` state.
+	#[clap(
+		long,
+		multiple_values = false,
+		parse(try_from_str = crate::parse::child_trie)
+	)]
+	pub child_trie: Option<Vec<u8>>,
+
+	/// Write the storage read during the upgrade to this file, in the folded-stack format
+	/// consumed by flamegraph tooling.
+	///
+	/// Each line has the form `pallet;storage size`, where `pallet` and `storage` are the hex
+	/// encoded hashed prefixes of the storage item, and `size` is the number of bytes of all keys
+	/// and values of that item contained in the storage proof.
+	#[clap(long)]
+	pub folded_output: Option<PathBuf>,
+
+	/// Fail unless the storage root after the upgrade equals this hash.
+	///
+	/// Useful to verify that a migration deterministically produces the same state.
+	#[clap(
+		long,
+		multiple_values = false,
+		parse(try_from_str = crate::parse::hash)
+	)]
+	pub expect_root: Option<String>,
+
+	/// Set the timestamp pallet's `Now` to this unix timestamp (in milliseconds) before the
+	/// upgrade.
+	///
+	/// Overrides the value at `twox_128("Timestamp") ++ twox_128("Now")`, which lets
+	/// time-dependent migrations be tested deterministically.
+	#[clap(long, parse(try_from_str = crate::parse::timestamp))]
+	pub mock_now: Option<u64>,
+
+	/// Set the system pallet's `Number` to this block number before the upgrade.
+	///
+	/// Overrides the value at `twox_128("System") ++ twox_128("Number")`, which lets migrations
+	/// reading `System::block_number()` behave the same regardless of the source of the state.
+	/// The number has to fit into the block number type of the runtime.
+	#[clap(long, parse(try_from_str = crate::parse::block_number))]
+	pub mock_block_number: Option<u64>,
+
+	/// Also run the upgrade with the runtime wasm blob at this path, and report how its weight
+	/// differs from the one of the local runtime.
+	///
+	/// Both runs use the same state, which is built a second time with the blob injected as
+	/// `:code:`. For the `live` state, this means the state is downloaded twice.
+	#[clap(long)]
+	pub compare_wasm: Option<PathBuf>,
+
+	/// If the upgrade panics or traps, also report the last line the runtime logged before, which
+	/// usually names the migration that crashed.
+	///
+	/// Only log lines enabled by the log filter of the CLI are seen.
+	#[clap(long)]
+	pub catch_panic: bool,
+
+	/// Report the value of this hex encoded storage key before and after the upgrade.
+	///
+	/// May be given multiple times. Absent values are shown as `<none>`.
+	#[clap(
+		long,
+		multiple_occurrences = true,
+		multiple_values = false,
+		parse(try_from_str = crate::parse::hex_bytes)
+	)]
+	pub watch_key: Vec<Vec<u8>>,
+
+	/// Run the upgrade a second time on top of the state it produced, and fail if that second
+	/// run changes any storage.
+	///
+	/// The keys changed by the second run are reported. The second run also fails if its
+	/// `ref_time` weight exceeds that of reading the storage version of every pallet, see
+	/// `--idempotent-read-weight`.
+	#[clap(long)]
+	pub assert_idempotent: bool,
+
+	/// The `ref_time` weight of a single storage read (in picoseconds), used to bound the weight
+	/// of the second run of `--assert-idempotent`.
+	///
+	/// Defaults to the read weight of `RocksDbWeight`.
+	#[clap(long, default_value = "25000000")]
+	pub idempotent_read_weight: u64,
+
+	/// Abort with an error if the upgrade takes longer than this many seconds.
+	///
+	/// The upgrade itself can't be interrupted, so the whole process exits once the limit is
+	/// hit.
+	#[clap(long)]
+	pub max_duration: Option<u64>,
+
+	/// Set the on-chain storage version of a pallet before the upgrade, in the form
+	/// `<pallet>=<version>`.
+	///
+	/// May be given multiple times. Useful to run the branch of a migration gated on a specific
+	/// version. The pallet must be part of the metadata of the local runtime.
+	#[clap(
+		long,
+		multiple_occurrences = true,
+		multiple_values = false,
+		parse(try_from_str = crate::parse::storage_version_override)
+	)]
+	pub set_storage_version: Vec<(String, u16)>,
+
+	/// Fail unless the upgrade deposited this hex encoded, SCALE encoded `RuntimeEvent`.
+	///
+	/// May be given multiple times. The event and the records of `System::Events` are decoded
+	/// against the metadata of the runtime, and only the records added by the upgrade are
+	/// considered, not those already part of the state.
+	#[clap(
+		long,
+		multiple_occurrences = true,
+		multiple_values = false,
+		parse(try_from_str = crate::parse::hex_bytes)
+	)]
+	pub expect_event: Vec<Vec<u8>>,
+
+	/// Print the storage access metrics of the upgrade to stdout, for CI to keep track of.
+	///
+	/// The metrics are printed as a single line of the form `try-runtime-metrics
+	/// accessed_keys=<n> written_keys=<n> proof_bytes=<n> ref_time=<n> proof_size=<n>`, which is
+	/// kept stable. The accessed keys are those contained in the storage proof of the upgrade.
+	#[clap(long)]
+	pub emit_metrics: bool,
+
 	/// The state type to use.
 	#[clap(subcommand)]
 	pub state: State,
@@ -52,11 +211,7 @@ where
 	let executor = build_executor(&shared, &config);
 	let execution = shared.execution;
 
-	let ext = {
-		let builder = command.state.builder::<Block>()?.state_version(shared.state_version);
-		let (code_key, code) = extract_code(&config.chain_spec)?;
-		builder.inject_hashed_key_value(&[(code_key, code)]).build().await?
-	};
+	let mut ext = build_ext::<Block>(&command, &shared, extract_code(&config.chain_spec)?).await?;
 
 	if let Some(uri) = command.state.live_uri() {
 		let (expected_spec_name, expected_spec_version, _) =
@@ -70,14 +225,56 @@ where
 		.await;
 	}
 
-	let (_, encoded_result) = state_machine_call_with_proof::<Block, ExecDispatch>(
-		&ext,
-		&executor,
-		execution,
-		"TryRuntime_on_runtime_upgrade",
-		&[],
-		Default::default(), // we don't really need any extensions here.
-	)?;
+	if !command.set_storage_version.is_empty() {
+		let pallets = pallet_names::<Block, ExecDispatch>(&ext, &executor, execution)?;
+		for (pallet, version) in &command.set_storage_version {
+			if !pallets.contains(pallet) {
+				return Err(format!(
+					"pallet {} of `--set-storage-version` is not part of the runtime",
+					pallet
+				)
+				.into())
+			}
+			log::info!(target: LOG_TARGET, "setting the storage version of {} to {}.", pallet, version);
+		}
+	}
+
+	let upgrade = || {
+		state_machine_call_with_storage_proof::<Block, ExecDispatch>(
+			&ext,
+			&executor,
+			execution,
+			"TryRuntime_on_runtime_upgrade",
+			&[],
+			Default::default(), // we don't really need any extensions here.
+		)
+	};
+	let watchdog = command.max_duration.map(|secs| watchdog(Duration::from_secs(secs)));
+	let (changes, encoded_result, proof) = if command.catch_panic {
+		// the runtime is executed on this thread, so its logs pass through the default dispatcher
+		// of this thread.
+		let last_line = Arc::new(Mutex::new(None));
+		let dispatch = tracing::dispatcher::get_default(|inner| {
+			tracing::Dispatch::new(LastLogLine { inner: inner.clone(), last: last_line.clone() })
+		});
+		tracing::dispatcher::with_default(&dispatch, upgrade).map_err(|e| {
+			let last = last_line
+				.lock()
+				.ok()
+				.and_then(|last| last.clone())
+				.unwrap_or_else(|| "<none>".into());
+			log::error!(
+				target: LOG_TARGET,
+				"TryRuntime_on_runtime_upgrade failed, the last line logged before was: {}",
+				last,
+			);
+			format!("the runtime upgrade failed: {}, last log line: {}", e, last)
+		})?
+	} else {
+		upgrade()?
+	};
+	// the upgrade returned, so stop the watchdog.
+	drop(watchdog);
 
 	let (weight, total_weight) = <(Weight, Weight) as Decode>::decode(&mut &*encoded_result)
 		.map_err(|e| format!("failed to decode weight: {:?}", e))?;
@@ -90,5 +287,534 @@ where
 		(weight.proof_size() as f64 / total_weight.proof_size().max(1) as f64) * 100.0,
 	);
 
+	if command.emit_metrics {
+		let accessed_keys = proven_values(&ext, proof.clone())?.len();
+		let written_keys: usize = storage_collections(&changes)
+			.iter()
+			.map(|(_, collection)| collection.len())
+			.sum();
+		println!(
+			"try-runtime-metrics accessed_keys={} written_keys={} proof_bytes={} ref_time={} proof_size={}",
+			accessed_keys,
+			written_keys,
+			proof.encoded_size(),
+			weight.ref_time(),
+			weight.proof_size(),
+		);
+	}
+
+	for key in &command.watch_key {
+		let before = ext
+			.backend
+			.storage(key)
+			.map_err(|e| format!("failed to read watched key: {:?}", e))?;
+		let after = match changes.storage(key) {
+			Some(changed) => changed.map(|value| value.to_vec()),
+			None => before.clone(),
+		};
+		log::info!(
+			target: LOG_TARGET,
+			"watched key 0x{}: {} -> {}{}",
+			HexDisplay::from(key),
+			display_value(&before),
+			display_value(&after),
+			if before == after { " (unchanged)" } else { "" },
+		);
+	}
+
+	if !command.expect_event.is_empty() {
+		let events_key = [twox_128(b"System"), twox_128(b"Events")].concat();
+		let before = ext
+			.backend
+			.storage(&events_key)
+			.map_err(|e| format!("failed to read the events: {:?}", e))?;
+		let after = match changes.storage(&events_key) {
+			Some(changed) => changed.map(|value| value.to_vec()),
+			None => before.clone(),
+		};
+		let metadata = runtime_metadata::<Block, ExecDispatch>(&ext, &executor, execution)?;
+		let (event_ty, deposited) = upgrade_events(&metadata, &before, &after)?;
+		let mut missing = 0;
+		for encoded in &command.expect_event {
+			let event = decode_all(&metadata.types, event_ty, encoded).map_err(|e| {
+				format!("failed to decode expected event 0x{}: {}", HexDisplay::from(encoded), e)
+			})?;
+			if !deposited.contains(&event) {
+				missing += 1;
+				log::error!(
+					target: LOG_TARGET,
+					"expected event {} was not deposited by the upgrade.",
+					event,
+				);
+			}
+		}
+		if missing > 0 {
+			return Err(
+				format!("{} expected events were not deposited by the upgrade", missing).into()
+			)
+		}
+		log::info!(
+			target: LOG_TARGET,
+			"all {} expected events were deposited.",
+			command.expect_event.len(),
+		);
+	}
+
+	if let Some(expected) = &command.expect_root {
+		let expected = hash_of::<Block>(expected)?;
+		let root =
+			changes.storage_root(&ext.backend, &mut Default::default(), shared.state_version);
+		if root.as_ref() != expected.as_ref() {
+			return Err(format!(
+				"storage root after the upgrade is {:?}, expected {:?}",
+				root, expected
+			)
+			.into())
+		}
+		log::info!(target: LOG_TARGET, "storage root after the upgrade matches {:?}.", expected);
+	}
+
+	if command.assert_idempotent {
+		// the second run should do no more than read the storage version of each pallet.
+		let max_ref_time = (pallet_names::<Block, ExecDispatch>(&ext, &executor, execution)?.len()
+			as u64)
+			.saturating_mul(command.idempotent_read_weight);
+		// run against the upgraded state, then put the original one back for the other reports.
+		let upgraded = ext.backend.update(storage_collections(&changes), shared.state_version);
+		let original = std::mem::replace(&mut ext.backend, upgraded);
+		let result =
+			assert_idempotent::<Block, ExecDispatch>(&ext, &executor, execution, max_ref_time);
+		ext.backend = original;
+		result?;
+	}
+
+	if let Some(path) = &command.compare_wasm {
+		let code = std::fs::read(path)
+			.map_err(|e| format!("failed to read wasm blob {:?}: {:?}", path, e))?;
+		let code_key = StorageKey(well_known_keys::CODE.to_vec());
+		let compare_ext =
+			build_ext::<Block>(&command, &shared, (code_key, StorageData(code))).await?;
+		let (_, encoded_result) = state_machine_call::<Block, ExecDispatch>(
+			&compare_ext,
+			&executor,
+			execution,
+			"TryRuntime_on_runtime_upgrade",
+			&[],
+			Default::default(),
+		)?;
+		let (compare_weight, _) = <(Weight, Weight) as Decode>::decode(&mut &*encoded_result)
+			.map_err(|e| format!("failed to decode weight: {:?}", e))?;
+		log::info!(
+			target: LOG_TARGET,
+			"local runtime consumed ({} ps, {} byte), {:?} consumed ({} ps, {} byte). Difference = ({:+} ps, {:+} byte).",
+			weight.ref_time(), weight.proof_size(),
+			path, compare_weight.ref_time(), compare_weight.proof_size(),
+			compare_weight.ref_time() as i128 - weight.ref_time() as i128,
+			compare_weight.proof_size() as i128 - weight.proof_size() as i128,
+		);
+	}
+
+	if let Some(path) = &command.folded_output {
+		write_folded_proof(&ext, proof, path)?;
+		log::info!(target: LOG_TARGET, "folded storage proof written to {:?}.", path);
+	}
+
+	// The breakdown is optional, older runtimes simply don't expose it.
+	match state_machine_call::<Block, ExecDispatch>(
+		&ext,
+		&executor,
+		execution,
+		BREAKDOWN_METHOD,
+		&[],
+		Default::default(),
+	) {
+		Ok((_, encoded)) => {
+			let breakdown = <Vec<(Vec<u8>, Weight)> as Decode>::decode(&mut &*encoded)
+				.map_err(|e| format!("failed to decode migration weight breakdown: {:?}", e))?;
+			render_breakdown(breakdown, command.per_migration_max);
+		},
+		Err(e) => log::info!(
+			target: LOG_TARGET,
+			"runtime does not provide a per-migration weight breakdown ({}), skipping.",
+			e,
+		),
+	}
+
+	Ok(())
+}
+
+/// Run the upgrade on `ext`, which is expected to be upgraded already, and fail if it changes any
+/// storage or consumes more than `max_ref_time`.
+fn assert_idempotent<Block: BlockT, ExecDispatch: NativeExecutionDispatch + 'static>(
+	ext: &TestExternalities,
+	executor: &NativeElseWasmExecutor<ExecDispatch>,
+	execution: sc_cli::ExecutionStrategy,
+	max_ref_time: u64,
+) -> sc_cli::Result<()> {
+	let (changes, encoded_result) = state_machine_call::<Block, ExecDispatch>(
+		ext,
+		executor,
+		execution,
+		"TryRuntime_on_runtime_upgrade",
+		&[],
+		Default::default(),
+	)?;
+	let (weight, _) = <(Weight, Weight) as Decode>::decode(&mut &*encoded_result)
+		.map_err(|e| format!("failed to decode weight: {:?}", e))?;
+	log::info!(
+		target: LOG_TARGET,
+		"second TryRuntime_on_runtime_upgrade consumed ({} ps, {} byte).",
+		weight.ref_time(),
+		weight.proof_size(),
+	);
+
+	// writes of the value already in storage are not changes.
+	let mut changed = 0;
+	for (maybe_child, collection) in storage_collections(&changes) {
+		for (key, value) in collection {
+			let current = match &maybe_child {
+				Some(child_info) => ext.backend.child_storage(child_info, &key),
+				None => ext.backend.storage(&key),
+			}
+			.map_err(|e| format!("failed to read storage: {:?}", e))?;
+			if current != value {
+				changed += 1;
+				log::error!(
+					target: LOG_TARGET,
+					"second upgrade changed {}0x{}: {} -> {}",
+					maybe_child
+						.as_ref()
+						.map(|child_info| format!(
+							"child-trie 0x{} key ",
+							HexDisplay::from(&child_info.storage_key())
+						))
+						.unwrap_or_default(),
+					HexDisplay::from(&key),
+					display_value(&current),
+					display_value(&value),
+				);
+			}
+		}
+	}
+
+	if changed > 0 {
+		return Err(format!(
+			"the upgrade is not idempotent, its second run changed {} keys",
+			changed
+		)
+		.into())
+	}
+	if weight.ref_time() > max_ref_time {
+		return Err(format!(
+			"the second run of the upgrade consumed {} ps, more than the {} ps of the storage \
+			version checks",
+			weight.ref_time(),
+			max_ref_time,
+		)
+		.into())
+	}
+	log::info!(
+		target: LOG_TARGET,
+		"second TryRuntime_on_runtime_upgrade changed no storage, within {} ps.",
+		max_ref_time,
+	);
 	Ok(())
 }
+
+/// The top-level and child-trie changes of `changes`, in the form backends are updated with.
+fn storage_collections(changes: &OverlayedChanges) -> Vec<(Option<ChildInfo>, StorageCollection)> {
+	let top = changes.changes().map(|(k, v)| (k.clone(), v.value().cloned())).collect();
+	let mut collections = vec![(None, top)];
+	for (child_changes, child_info) in changes.children() {
+		collections.push((
+			Some(child_info.clone()),
+			child_changes.map(|(k, v)| (k.clone(), v.value().cloned())).collect(),
+		));
+	}
+	collections
+}
+
+/// The type of a `RuntimeEvent`, along with the events of the records of the encoded
+/// `System::Events` which were added between `before` and `after`.
+///
+/// The events are kept in order of deposit, so the records of the original state come first.
+/// If the upgrade cleared them, all records after the upgrade are taken.
+fn upgrade_events(
+	metadata: &RuntimeMetadataV14,
+	before: &Option<Vec<u8>>,
+	after: &Option<Vec<u8>>,
+) -> sc_cli::Result<(u32, Vec<Value>)> {
+	let (_, records_ty) = storage_types(metadata, "System", "Events")?;
+	let records = |encoded: &Option<Vec<u8>>| -> sc_cli::Result<Vec<Value>> {
+		let encoded = match encoded {
+			Some(encoded) => encoded,
+			None => return Ok(vec![]),
+		};
+		match decode_all(&metadata.types, records_ty, encoded)
+			.map_err(|e| format!("failed to decode the events: {}", e))?
+		{
+			Value::Sequence(records) => Ok(records),
+			_ => Err("`System::Events` is not a sequence of records".into()),
+		}
+	};
+	let event_ty = match metadata.types.resolve(records_ty).map(|ty| ty.type_def()) {
+		Some(TypeDef::Sequence(records)) => metadata.types.resolve(records.type_param().id()),
+		_ => None,
+	}
+	.and_then(|record| match record.type_def() {
+		TypeDef::Composite(record) => record.fields().iter().find_map(|field| {
+			(field.name().map(String::as_str) == Some("event")).then(|| field.ty().id())
+		}),
+		_ => None,
+	})
+	.ok_or("the records of `System::Events` have no `event`")?;
+
+	let before = records(before)?;
+	let mut after = records(after)?;
+	if after.starts_with(&before) {
+		after.drain(..before.len());
+	}
+	let events = after.into_iter().filter_map(|record| record.field("event").cloned()).collect();
+	Ok((event_ty, events))
+}
+
+/// The hex encoding of a storage `value`, or `<none>` if it is absent.
+fn display_value(value: &Option<Vec<u8>>) -> String {
+	match value {
+		Some(value) => format!("0x{}", HexDisplay::from(value)),
+		None => "<none>".into(),
+	}
+}
+
+/// Exit the process unless the returned sender is dropped within `max_duration`.
+fn watchdog(max_duration: Duration) -> mpsc::Sender<()> {
+	let (guard, stop) = mpsc::channel::<()>();
+	std::thread::spawn(move || {
+		if let Err(mpsc::RecvTimeoutError::Timeout) = stop.recv_timeout(max_duration) {
+			log::error!(
+				target: LOG_TARGET,
+				"TryRuntime_on_runtime_upgrade did not finish within {:?} (`--max-duration`), \
+				aborting.",
+				max_duration,
+			);
+			std::process::exit(1);
+		}
+	});
+	guard
+}
+
+/// A tracing subscriber forwarding everything to `inner`, while keeping the last line logged.
+///
+/// The report of the runtime's panic handler is skipped, since its message is already part of
+/// the error of the call.
+struct LastLogLine {
+	inner: tracing::Dispatch,
+	last: Arc<Mutex<Option<String>>>,
+}
+
+/// Extracts the message of a log line.
+struct MessageVisitor(Option<String>);
+
+impl tracing::field::Visit for MessageVisitor {
+	fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn Debug) {
+		if field.name() == "message" {
+			self.0 = Some(format!("{:?}", value));
+		}
+	}
+}
+
+impl tracing::Subscriber for LastLogLine {
+	fn register_callsite(
+		&self,
+		metadata: &'static tracing::Metadata<'static>,
+	) -> tracing::subscriber::Interest {
+		self.inner.register_callsite(metadata)
+	}
+
+	fn enabled(&self, metadata: &tracing::Metadata<'_>) -> bool {
+		self.inner.enabled(metadata)
+	}
+
+	fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+		self.inner.new_span(span)
+	}
+
+	fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+		self.inner.record(span, values)
+	}
+
+	fn record_follows_from(&self, span: &tracing::span::Id, follows: &tracing::span::Id) {
+		self.inner.record_follows_from(span, follows)
+	}
+
+	fn event(&self, event: &tracing::Event<'_>) {
+		let mut visitor = MessageVisitor(None);
+		event.record(&mut visitor);
+		let target = event.metadata().target();
+		let panic_report =
+			|message: &String| target == "runtime" && message.starts_with("panicked at");
+		if let Some(message) = visitor.0.filter(|message| !panic_report(message)) {
+			if let Ok(mut last) = self.last.lock() {
+				*last = Some(format!("{}: {}", target, message));
+			}
+		}
+		self.inner.event(event)
+	}
+
+	fn enter(&self, span: &tracing::span::Id) {
+		self.inner.enter(span)
+	}
+
+	fn exit(&self, span: &tracing::span::Id) {
+		self.inner.exit(span)
+	}
+
+	fn clone_span(&self, id: &tracing::span::Id) -> tracing::span::Id {
+		self.inner.clone_span(id)
+	}
+
+	fn try_close(&self, id: tracing::span::Id) -> bool {
+		self.inner.try_close(id)
+	}
+
+	fn current_span(&self) -> tracing::span::Current {
+		self.inner.current_span()
+	}
+}
+
+/// Build the externalities to run the upgrade against, with `code` injected as the runtime.
+async fn build_ext<Block>(
+	command: &OnRuntimeUpgradeCmd,
+	shared: &SharedParams,
+	code: (StorageKey, StorageData),
+) -> sc_cli::Result<TestExternalities>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	Block::Hash: FromStr,
+	<Block::Hash as FromStr>::Err: Debug,
+	NumberFor<Block>: FromStr,
+	<NumberFor<Block> as FromStr>::Err: Debug,
+{
+	let mut builder = command.state.builder::<Block>()?.state_version(shared.state_version);
+	if let Some(child_root) = &command.child_trie {
+		if command.state.live_uri().is_none() {
+			return Err("`--child-trie` can only be used with the `live` state".into())
+		}
+		log::warn!(
+			target: LOG_TARGET,
+			"only fetching the child-trie {}, any top-level storage read by the migration will \
+			be absent.",
+			HexDisplay::from(child_root),
+		);
+		builder = builder.only_injected().inject_hashed_key(child_root);
+	}
+	if let Some(now) = command.mock_now {
+		log::info!(target: LOG_TARGET, "setting `Timestamp::Now` to {} ms.", now);
+		let now_key = [twox_128(b"Timestamp"), twox_128(b"Now")].concat();
+		builder =
+			builder.inject_hashed_key_value(&[(StorageKey(now_key), StorageData(now.encode()))]);
+	}
+	if let Some(number) = command.mock_block_number {
+		let encoded = NumberFor::<Block>::try_from(number)
+			.map_err(|_| {
+				format!(
+					"block number {} of `--mock-block-number` is too large for the runtime",
+					number
+				)
+			})?
+			.encode();
+		log::info!(target: LOG_TARGET, "setting `System::Number` to {}.", number);
+		let number_key = [twox_128(b"System"), twox_128(b"Number")].concat();
+		builder =
+			builder.inject_hashed_key_value(&[(StorageKey(number_key), StorageData(encoded))]);
+	}
+	let versions = command
+		.set_storage_version
+		.iter()
+		.map(|(pallet, version)| {
+			(StorageKey(storage_version_key(pallet)), StorageData(version.encode()))
+		})
+		.collect::<Vec<_>>();
+	Ok(builder
+		.inject_hashed_key_value(&versions)
+		.inject_hashed_key_value(&[code])
+		.build()
+		.await?)
+}
+
+/// Write the size of each storage item contained in `proof` to `path` as folded stacks.
+fn write_folded_proof(
+	ext: &TestExternalities,
+	proof: StorageProof,
+	path: &PathBuf,
+) -> sc_cli::Result<()> {
+	let mut sizes = BTreeMap::<String, usize>::new();
+	for (key, value) in proven_values(ext, proof)? {
+		*sizes.entry(folded_frame(&key)).or_default() += key.len() + value.len();
+	}
+
+	let folded = sizes
+		.into_iter()
+		.map(|(frame, size)| format!("{} {}\n", frame, size))
+		.collect::<String>();
+	std::fs::write(path, folded)
+		.map_err(|e| format!("failed to write folded proof to {:?}: {:?}", path, e).into())
+}
+
+/// The keys of `ext` whose values are contained in `proof`, along with those values.
+fn proven_values(
+	ext: &TestExternalities,
+	proof: StorageProof,
+) -> sc_cli::Result<Vec<(Vec<u8>, Vec<u8>)>> {
+	let proof_backend =
+		sp_state_machine::create_proof_check_backend::<BlakeTwo256>(*ext.backend.root(), proof)
+			.map_err(|e| format!("failed to create proof check backend: {:?}", e))?;
+
+	Ok(ext
+		.backend
+		.keys(&[])
+		.into_iter()
+		// keys which were not accessed can't be read from the proof.
+		.filter_map(|key| match proof_backend.storage(&key) {
+			Ok(Some(value)) => Some((key, value)),
+			_ => None,
+		})
+		.collect())
+}
+
+/// The `pallet;storage` frame of `key`, based on its hashed prefixes.
+fn folded_frame(key: &[u8]) -> String {
+	if key.starts_with(b":") {
+		return format!("well_known;{}", String::from_utf8_lossy(key))
+	}
+	match (key.get(..16), key.get(16..32)) {
+		(Some(pallet), Some(storage)) =>
+			format!("0x{};0x{}", HexDisplay::from(&pallet), HexDisplay::from(&storage)),
+		_ => format!("unknown;0x{}", HexDisplay::from(&key)),
+	}
+}
+
+/// Log the weight of each migration, heaviest first, warning about those above `max_ref_time`.
+fn render_breakdown(mut breakdown: Vec<(Vec<u8>, Weight)>, max_ref_time: Option<u64>) {
+	breakdown.sort_by(|(_, a), (_, b)| b.ref_time().cmp(&a.ref_time()));
+	for (id, weight) in breakdown {
+		let id = String::from_utf8_lossy(&id);
+		match max_ref_time {
+			Some(max) if weight.ref_time() > max => log::warn!(
+				target: LOG_TARGET,
+				"migration {} consumed ({} ps, {} byte), exceeding the maximum of {} ps.",
+				id,
+				weight.ref_time(),
+				weight.proof_size(),
+				max,
+			),
+			_ => log::info!(
+				target: LOG_TARGET,
+				"migration {} consumed ({} ps, {} byte).",
+				id,
+				weight.ref_time(),
+				weight.proof_size(),
+			),
+		}
+	}
+}
@@ -0,0 +1,98 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{build_executor, metadata::runtime_metadata, SharedParams, State, LOG_TARGET};
+use parity_scale_codec::Decode;
+use remote_externalities::TestExternalities;
+use sc_executor::{NativeElseWasmExecutor, NativeExecutionDispatch};
+use sc_service::Configuration;
+use sp_core::{storage::well_known_keys, twox_128};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use sp_state_machine::Backend;
+use std::{fmt::Debug, str::FromStr};
+
+/// The postfix of the key the storage version of a pallet is stored under, see
+/// `frame_support::traits::STORAGE_VERSION_STORAGE_KEY_POSTFIX`.
+const STORAGE_VERSION_KEY_POSTFIX: &[u8] = b":__STORAGE_VERSION__:";
+
+/// Configurations of the [`Command::StorageVersions`].
+#[derive(Debug, Clone, clap::Parser)]
+pub struct StorageVersionsCmd {
+	/// The state type to use.
+	#[clap(subcommand)]
+	pub state: State,
+}
+
+pub(crate) async fn storage_versions<Block, ExecDispatch>(
+	shared: SharedParams,
+	command: StorageVersionsCmd,
+	config: Configuration,
+) -> sc_cli::Result<()>
+where
+	Block: BlockT + serde::de::DeserializeOwned,
+	Block::Hash: FromStr,
+	<Block::Hash as FromStr>::Err: Debug,
+	NumberFor<Block>: FromStr,
+	<NumberFor<Block> as FromStr>::Err: Debug,
+	ExecDispatch: NativeExecutionDispatch + 'static,
+{
+	let executor = build_executor(&shared, &config);
+
+	// the pallet names are taken from the metadata of the code in state, not the local one.
+	let ext = command
+		.state
+		.builder::<Block>()?
+		.state_version(shared.state_version)
+		.inject_hashed_key(well_known_keys::CODE)
+		.build()
+		.await?;
+
+	let pallets = pallet_names::<Block, ExecDispatch>(&ext, &executor, shared.execution)?;
+
+	log::info!(target: LOG_TARGET, "{:<32} storage version", "pallet");
+	for pallet in pallets {
+		let version = ext
+			.backend
+			.storage(&storage_version_key(&pallet))
+			.map_err(|e| format!("failed to read the storage version of {}: {:?}", pallet, e))?
+			.map(|encoded| <u16 as Decode>::decode(&mut &*encoded))
+			.transpose()
+			.map_err(|e| format!("failed to decode the storage version of {}: {:?}", pallet, e))?;
+		match version {
+			Some(version) => log::info!(target: LOG_TARGET, "{:<32} {}", pallet, version),
+			// pallets which never set a version are at the default of zero.
+			None => log::info!(target: LOG_TARGET, "{:<32} 0 (unset)", pallet),
+		}
+	}
+
+	Ok(())
+}
+
+/// The names of the pallets of the runtime in `ext`, as found in its metadata.
+pub(crate) fn pallet_names<Block: BlockT, ExecDispatch: NativeExecutionDispatch + 'static>(
+	ext: &TestExternalities,
+	executor: &NativeElseWasmExecutor<ExecDispatch>,
+	execution: sc_cli::ExecutionStrategy,
+) -> sc_cli::Result<Vec<String>> {
+	let metadata = runtime_metadata::<Block, ExecDispatch>(ext, executor, execution)?;
+	Ok(metadata.pallets.into_iter().map(|pallet| pallet.name).collect())
+}
+
+/// The key the storage version of `pallet` is stored under.
+pub(crate) fn storage_version_key(pallet: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(STORAGE_VERSION_KEY_POSTFIX)].concat()
+}
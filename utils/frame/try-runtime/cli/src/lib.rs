@@ -294,11 +294,12 @@ use sp_runtime::{
 	traits::{Block as BlockT, NumberFor},
 	DeserializeOwned,
 };
-use sp_state_machine::{OverlayedChanges, StateMachine, TrieBackendBuilder};
+use sp_state_machine::{OverlayedChanges, StateMachine, StorageProof, TrieBackendBuilder};
 use sp_version::StateVersion;
 use std::{fmt::Debug, path::PathBuf, str::FromStr};
 
 mod commands;
+pub(crate) mod metadata;
 pub(crate) mod parse;
 pub(crate) const LOG_TARGET: &str = "try-runtime::cli";
 
@@ -381,6 +382,34 @@ pub enum Command {
 	/// initializes the state from the remote node, and starts applying that block, plus all the
 	/// blocks that follow, to the same growing state.
 	FollowChain(commands::follow_chain::FollowChainCmd),
+
+	/// Apply a single extrinsic on top of some state.
+	///
+	/// The extrinsic is applied without initializing a new block, directly on top of the given
+	/// state. Similar to [`Command::ExecuteBlock`], this command does not overwrite the code by
+	/// default, see `ApplyExtrinsicCmd::overwrite_wasm_code`.
+	///
+	/// This uses `TryRuntime_apply_extrinsic` if the runtime provides it, and otherwise the same
+	/// runtime api as normal block authoring, namely `BlockBuilder_apply_extrinsic`. The result
+	/// and the weight consumed by the extrinsic are reported.
+	ApplyExtrinsic(commands::apply_extrinsic::ApplyExtrinsicCmd),
+
+	/// Report the on-chain storage version of every pallet in some state.
+	///
+	/// The pallet names are taken from the metadata of the code in the given state. Pallets
+	/// which never set a storage version are reported at the default of zero.
+	///
+	/// Nothing is executed besides `Metadata_metadata`, which makes this useful to plan the
+	/// migrations of an upcoming upgrade.
+	StorageVersions(commands::storage_versions::StorageVersionsCmd),
+
+	/// Report the state of a single collection of the uniques pallet.
+	///
+	/// The details, metadata and configuration of the collection are reported, along with the
+	/// details and metadata of each of its items. The values are decoded against the metadata of
+	/// the code in the given state. If the pallet is part of the runtime under a different name,
+	/// it can be given with `DumpNftsCmd::pallet_name`.
+	DumpNfts(commands::dump_nfts::DumpNftsCmd),
 }
 
 /// Shared parameters of the `try-runtime` commands
@@ -579,6 +608,27 @@ impl TryRuntimeCmd {
 					config,
 				)
 				.await,
+			Command::ApplyExtrinsic(cmd) =>
+				commands::apply_extrinsic::apply_extrinsic::<Block, ExecDispatch>(
+					self.shared.clone(),
+					cmd.clone(),
+					config,
+				)
+				.await,
+			Command::StorageVersions(cmd) =>
+				commands::storage_versions::storage_versions::<Block, ExecDispatch>(
+					self.shared.clone(),
+					cmd.clone(),
+					config,
+				)
+				.await,
+			Command::DumpNfts(cmd) =>
+				commands::dump_nfts::dump_nfts::<Block, ExecDispatch>(
+					self.shared.clone(),
+					cmd.clone(),
+					config,
+				)
+				.await,
 		}
 	}
 }
@@ -756,6 +806,24 @@ pub(crate) fn state_machine_call_with_proof<Block: BlockT, D: NativeExecutionDis
 	data: &[u8],
 	extensions: Extensions,
 ) -> sc_cli::Result<(OverlayedChanges, Vec<u8>)> {
+	state_machine_call_with_storage_proof::<Block, D>(
+		ext, executor, execution, method, data, extensions,
+	)
+	.map(|(changes, encoded_results, _)| (changes, encoded_results))
+}
+
+/// Same as [`state_machine_call_with_proof`], but also returns the storage proof itself.
+pub(crate) fn state_machine_call_with_storage_proof<
+	Block: BlockT,
+	D: NativeExecutionDispatch + 'static,
+>(
+	ext: &TestExternalities,
+	executor: &NativeElseWasmExecutor<D>,
+	execution: sc_cli::ExecutionStrategy,
+	method: &'static str,
+	data: &[u8],
+	extensions: Extensions,
+) -> sc_cli::Result<(OverlayedChanges, Vec<u8>, StorageProof)> {
 	use parity_scale_codec::Encode;
 	use sp_core::hexdisplay::HexDisplay;
 
@@ -797,7 +865,7 @@ pub(crate) fn state_machine_call_with_proof<Block: BlockT, D: NativeExecutionDis
 	let compressed_proof = zstd::stream::encode_all(&compact_proof.encode()[..], 0)
 		.map_err(|e| format!("failed to generate compact proof {}: {:?}", method, e))?;
 
-	let proof_nodes = proof.into_nodes();
+	let proof_nodes = proof.clone().into_nodes();
 
 	let humanize = |s| {
 		if s < 1024 * 1024 {
@@ -824,7 +892,7 @@ pub(crate) fn state_machine_call_with_proof<Block: BlockT, D: NativeExecutionDis
 		"zstd-compressed compact proof {}",
 		humanize(compressed_proof.len()),
 	);
-	Ok((changes, encoded_results))
+	Ok((changes, encoded_results, proof))
 }
 
 /// Get the spec `(name, version)` from the local runtime.
@@ -0,0 +1,313 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utils for decoding storage against the type registry of the runtime metadata.
+
+use crate::state_machine_call;
+use frame_metadata::{
+	RuntimeMetadata, RuntimeMetadataPrefixed, RuntimeMetadataV14, StorageEntryType,
+};
+use parity_scale_codec::{Compact, Decode};
+use remote_externalities::TestExternalities;
+use sc_executor::{NativeElseWasmExecutor, NativeExecutionDispatch};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+use sp_core::hexdisplay::HexDisplay;
+use sp_runtime::traits::Block as BlockT;
+use std::fmt;
+
+/// A value decoded against the type registry of the metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Value {
+	Bool(bool),
+	Char(char),
+	Str(String),
+	Unsigned(u128),
+	Signed(i128),
+	/// A sequence or array of `u8`, or a 256 bit integer in its little endian encoding.
+	Bytes(Vec<u8>),
+	Sequence(Vec<Value>),
+	/// A struct or tuple, with the names of its fields if it has any.
+	Composite(Vec<(Option<String>, Value)>),
+	Variant(String, Vec<(Option<String>, Value)>),
+}
+
+impl Value {
+	/// The value of the field `name`, if this is a struct with such a field.
+	pub(crate) fn field(&self, name: &str) -> Option<&Value> {
+		match self {
+			Value::Composite(fields) => fields
+				.iter()
+				.find(|(field, _)| field.as_deref() == Some(name))
+				.map(|(_, value)| value),
+			_ => None,
+		}
+	}
+}
+
+impl fmt::Display for Value {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Value::Bool(value) => write!(f, "{}", value),
+			Value::Char(value) => write!(f, "{:?}", value),
+			Value::Str(value) => write!(f, "{:?}", value),
+			Value::Unsigned(value) => write!(f, "{}", value),
+			Value::Signed(value) => write!(f, "{}", value),
+			// names and metadata are mostly text, so show them as such where possible.
+			Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+				Ok(text) if !text.is_empty() && !text.chars().any(char::is_control) =>
+					write!(f, "{:?}", text),
+				_ => write!(f, "0x{}", HexDisplay::from(bytes)),
+			},
+			Value::Sequence(items) => {
+				write!(f, "[")?;
+				for (i, item) in items.iter().enumerate() {
+					if i > 0 {
+						write!(f, ", ")?;
+					}
+					write!(f, "{}", item)?;
+				}
+				write!(f, "]")
+			},
+			// newtypes are shown as the value they wrap.
+			Value::Composite(fields) => match &fields[..] {
+				[(None, value)] => write!(f, "{}", value),
+				_ => write_fields(f, fields),
+			},
+			Value::Variant(name, fields) => {
+				write!(f, "{}", name)?;
+				if fields.is_empty() {
+					return Ok(())
+				}
+				if fields.iter().any(|(name, _)| name.is_some()) {
+					write!(f, " ")?;
+				}
+				write_fields(f, fields)
+			},
+		}
+	}
+}
+
+/// Writes `fields` as `{ a: .., b: .. }` if they are named, and as `(.., ..)` otherwise.
+fn write_fields(f: &mut fmt::Formatter, fields: &[(Option<String>, Value)]) -> fmt::Result {
+	let named = fields.iter().any(|(name, _)| name.is_some());
+	write!(f, "{}", if named { "{ " } else { "(" })?;
+	for (i, (name, value)) in fields.iter().enumerate() {
+		if i > 0 {
+			write!(f, ", ")?;
+		}
+		match name {
+			Some(name) => write!(f, "{}: {}", name, value)?,
+			None if named => write!(f, "_: {}", value)?,
+			None => write!(f, "{}", value)?,
+		}
+	}
+	write!(f, "{}", if named { " }" } else { ")" })
+}
+
+/// The V14 metadata of the runtime in `ext`, as returned by `Metadata_metadata`.
+pub(crate) fn runtime_metadata<Block: BlockT, ExecDispatch: NativeExecutionDispatch + 'static>(
+	ext: &TestExternalities,
+	executor: &NativeElseWasmExecutor<ExecDispatch>,
+	execution: sc_cli::ExecutionStrategy,
+) -> sc_cli::Result<RuntimeMetadataV14> {
+	let (_, encoded_result) = state_machine_call::<Block, ExecDispatch>(
+		ext,
+		executor,
+		execution,
+		"Metadata_metadata",
+		&[],
+		Default::default(),
+	)?;
+	let encoded_metadata = <Vec<u8> as Decode>::decode(&mut &*encoded_result)
+		.map_err(|e| format!("failed to decode opaque metadata: {:?}", e))?;
+	match RuntimeMetadataPrefixed::decode(&mut &*encoded_metadata)
+		.map_err(|e| format!("failed to decode metadata: {:?}", e))?
+		.1
+	{
+		RuntimeMetadata::V14(metadata) => Ok(metadata),
+		_ => Err("only metadata V14 is supported".into()),
+	}
+}
+
+/// The types of the keys and of the value of the storage item `entry` of `pallet`.
+///
+/// Plain storage values have no keys, maps have one type per hasher.
+pub(crate) fn storage_types(
+	metadata: &RuntimeMetadataV14,
+	pallet: &str,
+	entry: &str,
+) -> sc_cli::Result<(Vec<u32>, u32)> {
+	let entry = metadata
+		.pallets
+		.iter()
+		.find(|p| p.name == pallet)
+		.and_then(|p| p.storage.as_ref())
+		.and_then(|storage| storage.entries.iter().find(|e| e.name == entry))
+		.ok_or_else(|| format!("storage item {}::{} is not part of the metadata", pallet, entry))?;
+	match &entry.ty {
+		StorageEntryType::Plain(value) => Ok((vec![], value.id())),
+		StorageEntryType::Map { hashers, key, value } if hashers.len() == 1 =>
+			Ok((vec![key.id()], value.id())),
+		// maps with multiple keys take a tuple of them.
+		StorageEntryType::Map { key, value, .. } => match metadata.types.resolve(key.id()) {
+			Some(ty) => match ty.type_def() {
+				TypeDef::Tuple(keys) =>
+					Ok((keys.fields().iter().map(|key| key.id()).collect(), value.id())),
+				_ => Err(format!("the keys of {}::{} are not a tuple", pallet, entry.name).into()),
+			},
+			None => Err(format!("type {} not found in the metadata", key.id()).into()),
+		},
+	}
+}
+
+/// Decodes all of `encoded` as a value of the type `ty`.
+///
+/// Fails if anything is left of `encoded` afterwards.
+pub(crate) fn decode_all(
+	registry: &PortableRegistry,
+	ty: u32,
+	mut encoded: &[u8],
+) -> Result<Value, String> {
+	let value = decode_value(registry, ty, &mut encoded)?;
+	if !encoded.is_empty() {
+		return Err(format!("{} bytes left after decoding", encoded.len()))
+	}
+	Ok(value)
+}
+
+/// Decodes a value of the type `ty` from the start of `input`.
+pub(crate) fn decode_value(
+	registry: &PortableRegistry,
+	ty: u32,
+	input: &mut &[u8],
+) -> Result<Value, String> {
+	let ty = registry
+		.resolve(ty)
+		.ok_or_else(|| format!("type {} not found in the metadata", ty))?;
+	Ok(match ty.type_def() {
+		TypeDef::Composite(composite) =>
+			Value::Composite(decode_fields(registry, composite.fields(), input)?),
+		TypeDef::Variant(variants) => {
+			let index = decode::<u8>(input)?;
+			let variant = variants
+				.variants()
+				.iter()
+				.find(|variant| variant.index() == index)
+				.ok_or_else(|| format!("no variant with index {}", index))?;
+			Value::Variant(
+				variant.name().clone(),
+				decode_fields(registry, variant.fields(), input)?,
+			)
+		},
+		TypeDef::Sequence(sequence) => {
+			let len = decode::<Compact<u32>>(input)?.0;
+			decode_items(registry, sequence.type_param().id(), len, input)?
+		},
+		TypeDef::Array(array) =>
+			decode_items(registry, array.type_param().id(), array.len(), input)?,
+		TypeDef::Tuple(tuple) => Value::Composite(
+			tuple
+				.fields()
+				.iter()
+				.map(|field| Ok((None, decode_value(registry, field.id(), input)?)))
+				.collect::<Result<_, String>>()?,
+		),
+		TypeDef::Primitive(primitive) => decode_primitive(primitive, input)?,
+		// the compact encoding is the same for all unsigned integers it is used with.
+		TypeDef::Compact(_) => Value::Unsigned(decode::<Compact<u128>>(input)?.0),
+		TypeDef::BitSequence(bits) => {
+			let store_bytes =
+				match registry.resolve(bits.bit_store_type().id()).map(|ty| ty.type_def()) {
+					Some(TypeDef::Primitive(TypeDefPrimitive::U8)) => 1,
+					Some(TypeDef::Primitive(TypeDefPrimitive::U16)) => 2,
+					Some(TypeDef::Primitive(TypeDefPrimitive::U32)) => 4,
+					Some(TypeDef::Primitive(TypeDefPrimitive::U64)) => 8,
+					_ => return Err("unsupported bit store type".into()),
+				};
+			let len = decode::<Compact<u32>>(input)?.0 as usize;
+			Value::Bytes(take(
+				input,
+				(len + store_bytes * 8 - 1) / (store_bytes * 8) * store_bytes,
+			)?)
+		},
+	})
+}
+
+fn decode_fields(
+	registry: &PortableRegistry,
+	fields: &[Field<PortableForm>],
+	input: &mut &[u8],
+) -> Result<Vec<(Option<String>, Value)>, String> {
+	fields
+		.iter()
+		.map(|field| Ok((field.name().cloned(), decode_value(registry, field.ty().id(), input)?)))
+		.collect()
+}
+
+/// Decodes `len` items of the type `ty`, keeping them as bytes if they are `u8`s.
+fn decode_items(
+	registry: &PortableRegistry,
+	ty: u32,
+	len: u32,
+	input: &mut &[u8],
+) -> Result<Value, String> {
+	if let Some(TypeDef::Primitive(TypeDefPrimitive::U8)) =
+		registry.resolve(ty).map(|ty| ty.type_def())
+	{
+		return Ok(Value::Bytes(take(input, len as usize)?))
+	}
+	(0..len)
+		.map(|_| decode_value(registry, ty, input))
+		.collect::<Result<_, _>>()
+		.map(Value::Sequence)
+}
+
+fn decode_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Result<Value, String> {
+	Ok(match primitive {
+		TypeDefPrimitive::Bool => Value::Bool(decode(input)?),
+		TypeDefPrimitive::Char => {
+			let code = decode::<u32>(input)?;
+			Value::Char(char::from_u32(code).ok_or_else(|| format!("invalid char {}", code))?)
+		},
+		TypeDefPrimitive::Str => Value::Str(decode(input)?),
+		TypeDefPrimitive::U8 => Value::Unsigned(decode::<u8>(input)?.into()),
+		TypeDefPrimitive::U16 => Value::Unsigned(decode::<u16>(input)?.into()),
+		TypeDefPrimitive::U32 => Value::Unsigned(decode::<u32>(input)?.into()),
+		TypeDefPrimitive::U64 => Value::Unsigned(decode::<u64>(input)?.into()),
+		TypeDefPrimitive::U128 => Value::Unsigned(decode(input)?),
+		TypeDefPrimitive::I8 => Value::Signed(decode::<i8>(input)?.into()),
+		TypeDefPrimitive::I16 => Value::Signed(decode::<i16>(input)?.into()),
+		TypeDefPrimitive::I32 => Value::Signed(decode::<i32>(input)?.into()),
+		TypeDefPrimitive::I64 => Value::Signed(decode::<i64>(input)?.into()),
+		TypeDefPrimitive::I128 => Value::Signed(decode(input)?),
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => Value::Bytes(take(input, 32)?),
+	})
+}
+
+fn decode<T: Decode>(input: &mut &[u8]) -> Result<T, String> {
+	T::decode(input).map_err(|e| format!("{:?}", e))
+}
+
+/// Takes the first `len` bytes of `input`.
+fn take(input: &mut &[u8], len: usize) -> Result<Vec<u8>, String> {
+	if input.len() < len {
+		return Err(format!("expected {} more bytes, found {}", len, input.len()))
+	}
+	let (taken, rest) = input.split_at(len);
+	*input = rest;
+	Ok(taken.to_vec())
+}
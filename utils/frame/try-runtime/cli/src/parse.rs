@@ -17,6 +17,7 @@
 
 //! Utils for parsing user input
 
+use sp_core::storage::{well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX, ChildInfo, ChildType};
 use sp_version::StateVersion;
 
 pub(crate) fn hash(block_hash: &str) -> Result<String, String> {
@@ -36,6 +37,37 @@ pub(crate) fn hash(block_hash: &str) -> Result<String, String> {
 	}
 }
 
+pub(crate) fn hex_bytes(s: &str) -> Result<Vec<u8>, &'static str> {
+	sp_core::bytes::from_hex(s).map_err(|_| "not a valid hex string")
+}
+
+/// Parse a unix timestamp in milliseconds, which must lie after the epoch and before the
+/// year 10000.
+pub(crate) fn timestamp(s: &str) -> Result<u64, &'static str> {
+	const MAX_MILLIS: u64 = 253_402_300_799_999;
+	match s.parse::<u64>() {
+		Ok(millis) if millis > 0 && millis <= MAX_MILLIS => Ok(millis),
+		Ok(_) => Err("timestamp must be a positive number of milliseconds before the year 10000"),
+		Err(_) => Err("timestamp must be a number of milliseconds"),
+	}
+}
+
+/// Parse a block number, which the runtime may still reject as too large for its block numbers.
+pub(crate) fn block_number(s: &str) -> Result<u64, &'static str> {
+	s.parse::<u64>().map_err(|_| "block number must be a non-negative number")
+}
+
+/// Parse a `<pallet>=<version>` pair, as used to override the storage version of a pallet.
+pub(crate) fn storage_version_override(s: &str) -> Result<(String, u16), &'static str> {
+	let (pallet, version) =
+		s.split_once('=').ok_or("expected the form `<pallet>=<storage version>`")?;
+	if pallet.is_empty() {
+		return Err("pallet name must not be empty")
+	}
+	let version = version.parse::<u16>().map_err(|_| "storage version must be a number")?;
+	Ok((pallet.to_string(), version))
+}
+
 pub(crate) fn url(s: &str) -> Result<String, &'static str> {
 	if s.starts_with("ws://") || s.starts_with("wss://") {
 		// could use Url crate as well, but lets keep it simple for now.
@@ -51,3 +83,21 @@ pub(crate) fn state_version(s: &str) -> Result<StateVersion, &'static str> {
 		.and_then(StateVersion::try_from)
 		.map_err(|_| "Invalid state version.")
 }
+
+/// Parse a hex encoded child-trie key into the prefixed key under which its root is stored.
+///
+/// Both the plain child storage key and the one prefixed with `:child_storage:default:` are
+/// accepted.
+pub(crate) fn child_trie(s: &str) -> Result<Vec<u8>, &'static str> {
+	let key = sp_core::bytes::from_hex(s).map_err(|_| "child trie key must be valid hex")?;
+	let prefixed = if key.starts_with(DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+		sp_core::storage::PrefixedStorageKey::new(key)
+	} else {
+		ChildInfo::new_default(&key).prefixed_storage_key()
+	};
+	match ChildType::from_prefixed_key(&prefixed) {
+		Some((ChildType::ParentKeyId, storage_key)) if !storage_key.is_empty() =>
+			Ok(prefixed.into_inner()),
+		_ => Err("not a valid default child trie key"),
+	}
+}